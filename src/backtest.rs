@@ -0,0 +1,427 @@
+use crate::{
+    models::PortfolioAllocation,
+    error::TradingBotError,
+    config::TradingConfig,
+    utils::{calculate_sharpe_ratio, calculate_max_drawdown},
+};
+use chrono::{DateTime, Utc, NaiveDate};
+use log::info;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone)]
+pub struct PriceBar {
+    pub timestamp: DateTime<Utc>,
+    pub open: f64,
+    pub high: f64,
+    pub low: f64,
+    pub close: f64,
+    pub volume: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct DayPnl {
+    pub date: NaiveDate,
+    pub equity: f64,
+    pub pnl: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct AssetContribution {
+    pub ticker: String,
+    pub starting_value: f64,
+    pub ending_value: f64,
+    pub contribution: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct BacktestResult {
+    pub starting_equity: f64,
+    pub final_equity: f64,
+    pub equity_curve: Vec<f64>,
+    pub realized_sharpe: f64,
+    pub realized_max_drawdown: f64,
+    pub win_rate: f64,
+    pub per_asset_contribution: Vec<AssetContribution>,
+    pub days_breakdown: Vec<DayPnl>,
+}
+
+/// Summary trade-stats derived from a completed `BacktestResult`.
+#[derive(Debug, Clone)]
+pub struct TradeStatsReport {
+    pub total_return: f64,
+    pub cagr: f64,
+    pub annualized_volatility: f64,
+    pub sharpe_ratio: f64,
+    pub sortino_ratio: f64,
+    pub max_drawdown: f64,
+    pub win_rate: f64,
+    pub profit_factor: f64,
+    pub longest_drawdown_days: usize,
+}
+
+/// Replays historical OHLCV bars through a fixed set of allocations to produce
+/// realized metrics, rather than the forward-looking estimates in `PortfolioSummary`.
+pub struct Backtester {
+    config: TradingConfig,
+}
+
+impl Backtester {
+    pub fn new(config: TradingConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn run(
+        &self,
+        allocations: &[PortfolioAllocation],
+        price_history: &HashMap<String, Vec<PriceBar>>,
+    ) -> Result<BacktestResult, TradingBotError> {
+        info!("🧪 Backtesting {} allocations...", allocations.len());
+
+        if allocations.is_empty() {
+            return Err(TradingBotError::Calculation("No allocations to backtest".to_string()));
+        }
+
+        let num_bars = allocations.iter()
+            .filter_map(|a| price_history.get(&a.ticker))
+            .map(|bars| bars.len())
+            .min()
+            .unwrap_or(0);
+
+        if num_bars < 2 {
+            return Err(TradingBotError::DataProcessing("Not enough price history to backtest".to_string()));
+        }
+
+        let mut shares: HashMap<String, f64> = HashMap::new();
+        let mut starting_value: HashMap<String, f64> = HashMap::new();
+        let mut allocated_total = 0.0;
+
+        for alloc in allocations {
+            let bars = price_history.get(&alloc.ticker).ok_or_else(|| {
+                TradingBotError::DataProcessing(format!("No price history for {}", alloc.ticker))
+            })?;
+            let first_close = bars[0].close;
+            let qty = if first_close > 0.0 { alloc.dollar_allocation / first_close } else { 0.0 };
+            shares.insert(alloc.ticker.clone(), qty);
+            starting_value.insert(alloc.ticker.clone(), alloc.dollar_allocation);
+            allocated_total += alloc.dollar_allocation;
+        }
+
+        let starting_equity = self.config.default_portfolio_value;
+        let cash = starting_equity - allocated_total;
+
+        let mut equity_curve = Vec::with_capacity(num_bars);
+        let mut day_points: Vec<(NaiveDate, f64)> = Vec::with_capacity(num_bars);
+
+        for i in 0..num_bars {
+            let mut equity = cash;
+            let mut timestamp = Utc::now();
+            for alloc in allocations {
+                let qty = shares[&alloc.ticker];
+                let bar = &price_history[&alloc.ticker][i];
+                equity += qty * bar.close;
+                timestamp = bar.timestamp;
+            }
+            equity_curve.push(equity);
+            day_points.push((timestamp.date_naive(), equity));
+        }
+
+        let final_equity = *equity_curve.last().unwrap();
+
+        let period_returns: Vec<f64> = equity_curve.windows(2)
+            .map(|w| if w[0] != 0.0 { (w[1] - w[0]) / w[0] } else { 0.0 })
+            .collect();
+
+        let per_period_rf = self.config.risk_free_rate / self.config.lookback_days as f64;
+        let realized_sharpe = calculate_sharpe_ratio(&period_returns, per_period_rf);
+        let realized_max_drawdown = calculate_max_drawdown(&equity_curve);
+
+        let winning_periods = period_returns.iter().filter(|&&r| r > 0.0).count();
+        let win_rate = if !period_returns.is_empty() {
+            winning_periods as f64 / period_returns.len() as f64
+        } else {
+            0.0
+        };
+
+        let per_asset_contribution = allocations.iter().map(|alloc| {
+            let qty = shares[&alloc.ticker];
+            let last_close = price_history[&alloc.ticker][num_bars - 1].close;
+            let ending_value = qty * last_close;
+            let starting = starting_value[&alloc.ticker];
+            AssetContribution {
+                ticker: alloc.ticker.clone(),
+                starting_value: starting,
+                ending_value,
+                contribution: ending_value - starting,
+            }
+        }).collect();
+
+        let days_breakdown = Self::bucket_by_day(&day_points, starting_equity);
+
+        info!("✅ Backtest complete: final equity ${:.2}, realized Sharpe {:.2}", final_equity, realized_sharpe);
+
+        Ok(BacktestResult {
+            starting_equity,
+            final_equity,
+            equity_curve,
+            realized_sharpe,
+            realized_max_drawdown,
+            win_rate,
+            per_asset_contribution,
+            days_breakdown,
+        })
+    }
+
+    /// Like `run`, but every `rebalance_every_days` bars resets each position to
+    /// its initial target weight (recomputing share counts at that bar's close)
+    /// instead of letting the weights drift. The target weights themselves stay
+    /// those of the initial allocation; they are not recomputed from recent bars.
+    pub fn run_with_rebalancing(
+        &self,
+        allocations: &[PortfolioAllocation],
+        price_history: &HashMap<String, Vec<PriceBar>>,
+        rebalance_every_days: usize,
+    ) -> Result<BacktestResult, TradingBotError> {
+        info!("🧪 Backtesting {} allocations with rebalancing every {} days...", allocations.len(), rebalance_every_days);
+
+        if allocations.is_empty() {
+            return Err(TradingBotError::Calculation("No allocations to backtest".to_string()));
+        }
+        if rebalance_every_days == 0 {
+            return Err(TradingBotError::Calculation("rebalance_every_days must be positive".to_string()));
+        }
+
+        let num_bars = allocations.iter()
+            .filter_map(|a| price_history.get(&a.ticker))
+            .map(|bars| bars.len())
+            .min()
+            .unwrap_or(0);
+
+        if num_bars < 2 {
+            return Err(TradingBotError::DataProcessing("Not enough price history to backtest".to_string()));
+        }
+
+        let starting_equity = self.config.default_portfolio_value;
+        let total_weight: f64 = allocations.iter().map(|a| a.dollar_allocation).sum::<f64>() / starting_equity;
+        let target_weights: HashMap<String, f64> = allocations.iter()
+            .map(|a| (a.ticker.clone(), if starting_equity > 0.0 { a.dollar_allocation / starting_equity } else { 0.0 }))
+            .collect();
+
+        let mut shares: HashMap<String, f64> = HashMap::new();
+        let mut starting_value: HashMap<String, f64> = HashMap::new();
+        let mut cash = starting_equity * (1.0 - total_weight);
+
+        for alloc in allocations {
+            let bars = &price_history[&alloc.ticker];
+            let qty = if bars[0].close > 0.0 { alloc.dollar_allocation / bars[0].close } else { 0.0 };
+            shares.insert(alloc.ticker.clone(), qty);
+            starting_value.insert(alloc.ticker.clone(), alloc.dollar_allocation);
+        }
+
+        let mut equity_curve = Vec::with_capacity(num_bars);
+        let mut day_points: Vec<(NaiveDate, f64)> = Vec::with_capacity(num_bars);
+
+        for i in 0..num_bars {
+            let mut equity = cash;
+            let mut timestamp = Utc::now();
+            for alloc in allocations {
+                let qty = shares[&alloc.ticker];
+                let bar = &price_history[&alloc.ticker][i];
+                equity += qty * bar.close;
+                timestamp = bar.timestamp;
+            }
+
+            if i > 0 && i % rebalance_every_days == 0 {
+                for alloc in allocations {
+                    let bar = &price_history[&alloc.ticker][i];
+                    let target_value = equity * target_weights[&alloc.ticker];
+                    let new_qty = if bar.close > 0.0 { target_value / bar.close } else { 0.0 };
+                    cash -= target_value - shares[&alloc.ticker] * bar.close;
+                    shares.insert(alloc.ticker.clone(), new_qty);
+                }
+            }
+
+            equity_curve.push(equity);
+            day_points.push((timestamp.date_naive(), equity));
+        }
+
+        let final_equity = *equity_curve.last().unwrap();
+
+        let period_returns: Vec<f64> = equity_curve.windows(2)
+            .map(|w| if w[0] != 0.0 { (w[1] - w[0]) / w[0] } else { 0.0 })
+            .collect();
+
+        let per_period_rf = self.config.risk_free_rate / self.config.lookback_days as f64;
+        let realized_sharpe = calculate_sharpe_ratio(&period_returns, per_period_rf);
+        let realized_max_drawdown = calculate_max_drawdown(&equity_curve);
+
+        let winning_periods = period_returns.iter().filter(|&&r| r > 0.0).count();
+        let win_rate = if !period_returns.is_empty() {
+            winning_periods as f64 / period_returns.len() as f64
+        } else {
+            0.0
+        };
+
+        let per_asset_contribution = allocations.iter().map(|alloc| {
+            let qty = shares[&alloc.ticker];
+            let last_close = price_history[&alloc.ticker][num_bars - 1].close;
+            let ending_value = qty * last_close;
+            let starting = starting_value[&alloc.ticker];
+            AssetContribution {
+                ticker: alloc.ticker.clone(),
+                starting_value: starting,
+                ending_value,
+                contribution: ending_value - starting,
+            }
+        }).collect();
+
+        let days_breakdown = Self::bucket_by_day(&day_points, starting_equity);
+
+        info!("✅ Rolling-rebalance backtest complete: final equity ${:.2}, realized Sharpe {:.2}", final_equity, realized_sharpe);
+
+        Ok(BacktestResult {
+            starting_equity,
+            final_equity,
+            equity_curve,
+            realized_sharpe,
+            realized_max_drawdown,
+            win_rate,
+            per_asset_contribution,
+            days_breakdown,
+        })
+    }
+
+    /// Derives headline trade-stats (CAGR, Sortino, profit factor, longest
+    /// drawdown) from a completed backtest's equity curve and day-by-day P&L.
+    pub fn generate_report(&self, result: &BacktestResult) -> TradeStatsReport {
+        let total_return = if result.starting_equity > 0.0 {
+            (result.final_equity - result.starting_equity) / result.starting_equity
+        } else {
+            0.0
+        };
+
+        let years = result.days_breakdown.len() as f64 / 252.0;
+        let cagr = if years > 0.0 && total_return > -1.0 {
+            (1.0 + total_return).powf(1.0 / years) - 1.0
+        } else {
+            0.0
+        };
+
+        let period_returns: Vec<f64> = result.equity_curve.windows(2)
+            .map(|w| if w[0] != 0.0 { (w[1] - w[0]) / w[0] } else { 0.0 })
+            .collect();
+
+        let annualized_volatility = Self::calculate_volatility(&period_returns) * (252.0f64).sqrt();
+        let sortino_ratio = Self::calculate_sortino_ratio(&period_returns, self.config.risk_free_rate);
+
+        let gains: f64 = result.days_breakdown.iter().filter(|d| d.pnl > 0.0).map(|d| d.pnl).sum();
+        let losses: f64 = result.days_breakdown.iter().filter(|d| d.pnl < 0.0).map(|d| d.pnl.abs()).sum();
+        let profit_factor = if losses > 0.0 { gains / losses } else if gains > 0.0 { f64::INFINITY } else { 0.0 };
+
+        let longest_drawdown_days = Self::longest_drawdown_run(&result.equity_curve);
+
+        TradeStatsReport {
+            total_return,
+            cagr,
+            annualized_volatility,
+            sharpe_ratio: result.realized_sharpe,
+            sortino_ratio,
+            max_drawdown: result.realized_max_drawdown,
+            win_rate: result.win_rate,
+            profit_factor,
+            longest_drawdown_days,
+        }
+    }
+
+    fn calculate_volatility(returns: &[f64]) -> f64 {
+        if returns.len() < 2 {
+            return 0.0;
+        }
+        let mean = returns.iter().sum::<f64>() / returns.len() as f64;
+        let variance = returns.iter().map(|&r| (r - mean).powi(2)).sum::<f64>() / (returns.len() - 1) as f64;
+        variance.sqrt()
+    }
+
+    fn calculate_sortino_ratio(returns: &[f64], risk_free_rate: f64) -> f64 {
+        if returns.is_empty() {
+            return 0.0;
+        }
+        let daily_rf = risk_free_rate / 252.0;
+        let avg_excess = returns.iter().map(|r| r - daily_rf).sum::<f64>() / returns.len() as f64;
+        let downside_variance = returns.iter()
+            .map(|r| (r - daily_rf).min(0.0).powi(2))
+            .sum::<f64>() / returns.len() as f64;
+        let downside_deviation = downside_variance.sqrt();
+
+        if downside_deviation > 0.0 {
+            avg_excess / downside_deviation
+        } else {
+            0.0
+        }
+    }
+
+    /// Longest consecutive run of trading days spent below the running peak.
+    fn longest_drawdown_run(equity_curve: &[f64]) -> usize {
+        let mut peak = f64::MIN;
+        let mut current_run = 0;
+        let mut longest_run = 0;
+
+        for &equity in equity_curve {
+            if equity >= peak {
+                peak = equity;
+                current_run = 0;
+            } else {
+                current_run += 1;
+                longest_run = longest_run.max(current_run);
+            }
+        }
+
+        longest_run
+    }
+
+    fn bucket_by_day(day_points: &[(NaiveDate, f64)], starting_equity: f64) -> Vec<DayPnl> {
+        let mut by_day: Vec<DayPnl> = Vec::new();
+
+        for &(date, equity) in day_points {
+            match by_day.last_mut() {
+                Some(last) if last.date == date => {
+                    last.equity = equity;
+                }
+                _ => {
+                    let previous_equity = by_day.last().map(|d| d.equity).unwrap_or(starting_equity);
+                    by_day.push(DayPnl {
+                        date,
+                        equity,
+                        pnl: equity - previous_equity,
+                    });
+                }
+            }
+        }
+
+        // Re-derive pnl now that each day's final equity is settled.
+        let mut previous_equity = starting_equity;
+        for day in &mut by_day {
+            let pnl = day.equity - previous_equity;
+            previous_equity = day.equity;
+            day.pnl = pnl;
+        }
+
+        by_day
+    }
+}
+
+/// Prints a `TradeStatsReport` in the same console-table style used by
+/// `TradingBot::display_analysis_results`.
+pub fn display_backtest_report(report: &TradeStatsReport) {
+    println!("\n{}", "=".repeat(60));
+    println!("🧪 BACKTEST TRADE-STATS REPORT");
+    println!("{}", "=".repeat(60));
+    println!("Total Return: {:.2}%", report.total_return * 100.0);
+    println!("CAGR: {:.2}%", report.cagr * 100.0);
+    println!("Annualized Volatility: {:.2}%", report.annualized_volatility * 100.0);
+    println!("Sharpe Ratio: {:.2}", report.sharpe_ratio);
+    println!("Sortino Ratio: {:.2}", report.sortino_ratio);
+    println!("Max Drawdown: {:.2}%", report.max_drawdown * 100.0);
+    println!("Win Rate: {:.2}%", report.win_rate * 100.0);
+    println!("Profit Factor: {:.2}", report.profit_factor);
+    println!("Longest Drawdown: {} days", report.longest_drawdown_days);
+}
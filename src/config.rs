@@ -1,4 +1,5 @@
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::path::Path;
 use crate::error::TradingBotError;
 
@@ -7,6 +8,11 @@ pub struct Config {
     pub python: PythonConfig,
     pub api: ApiConfig,
     pub trading: TradingConfig,
+    pub protections: ProtectionConfig,
+    pub notifications: NotificationConfig,
+    pub ga_optimizer: GaOptimizerConfig,
+    pub data_layer: DataLayerConfig,
+    pub execution: ExecutionConfig,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -33,6 +39,82 @@ pub struct TradingConfig {
     pub max_allocation_per_position: f64,
     pub risk_free_rate: f64,
     pub lookback_days: u32,
+    /// Number of tranches used to scale into/out of a position gradually.
+    pub scale_in_tranches: usize,
+    /// Concentration risk above this level triggers a full scale-out.
+    pub concentration_risk_threshold: f64,
+    /// Lookback window (in trading days) used to compute momentum.
+    pub momentum_days: u32,
+    /// How many top-momentum names to keep from the candidate universe.
+    pub num_stocks: usize,
+    /// Minimum momentum required to keep a candidate; 0.0 rejects negative
+    /// momentum, -1.0 allows everything through.
+    pub trend: f64,
+    /// Confidence level used for historical VaR/CVaR (e.g. 0.95 for a 95% VaR).
+    pub confidence_level: f64,
+    /// Reference index used for beta/alpha/tracking-error calculations (e.g. "^GSPC").
+    pub benchmark_symbol: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProtectionConfig {
+    pub stoploss_lookback_minutes: i64,
+    pub stoploss_trade_limit: usize,
+    pub stoploss_lock_minutes: i64,
+    pub max_drawdown_lookback_periods: usize,
+    pub max_drawdown_limit: f64,
+    pub max_drawdown_lock_minutes: i64,
+    pub cooldown_minutes: i64,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct NotificationConfig {
+    pub enabled: bool,
+    pub webhook_url: String,
+    /// One of "info", "warning", "error" - events below this severity are dropped.
+    pub min_severity: String,
+    /// Per-event on/off switches, keyed by event name (e.g. "portfolio_summary").
+    pub events: HashMap<String, bool>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ProviderSettings {
+    pub enabled: bool,
+    pub api_key: String,
+    /// Lower values are tried first during failover.
+    pub priority: u32,
+    pub rate_limit_per_minute: u32,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct DataLayerConfig {
+    /// How long a cached quote is considered fresh, in seconds.
+    pub cache_expire_seconds: i64,
+    /// Shared rate limit applied across every provider, in addition to each
+    /// provider's own per-provider limit.
+    pub global_rate_limit_per_minute: u32,
+    pub alpha_vantage: ProviderSettings,
+    pub finnhub: ProviderSettings,
+    pub twelve_data: ProviderSettings,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExecutionConfig {
+    /// REST endpoint for the live broker. Empty disables live trading.
+    pub broker_endpoint: String,
+    pub api_key: String,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct GaOptimizerConfig {
+    pub population_size: usize,
+    pub generations: usize,
+    /// Weight applied to total premium collected in the fitness function.
+    pub premium_weight: f64,
+    /// Weight applied to the average underlying volatility penalty.
+    pub volatility_penalty_weight: f64,
+    /// Weight applied to the collateral-overage penalty.
+    pub collateral_penalty_weight: f64,
 }
 
 impl Config {
@@ -71,6 +153,61 @@ impl Config {
                 max_allocation_per_position: 0.2,
                 risk_free_rate: 0.05,
                 lookback_days: 252,
+                scale_in_tranches: 4,
+                concentration_risk_threshold: 0.35,
+                momentum_days: 20,
+                num_stocks: 10,
+                trend: 0.0,
+                confidence_level: 0.95,
+                benchmark_symbol: "^GSPC".to_string(),
+            },
+            protections: ProtectionConfig {
+                stoploss_lookback_minutes: 60 * 24,
+                stoploss_trade_limit: 4,
+                stoploss_lock_minutes: 60 * 12,
+                max_drawdown_lookback_periods: 20,
+                max_drawdown_limit: 0.2,
+                max_drawdown_lock_minutes: 60 * 24,
+                cooldown_minutes: 60 * 6,
+            },
+            notifications: NotificationConfig {
+                enabled: false,
+                webhook_url: String::new(),
+                min_severity: "warning".to_string(),
+                events: HashMap::new(),
+            },
+            ga_optimizer: GaOptimizerConfig {
+                population_size: 60,
+                generations: 80,
+                premium_weight: 1.0,
+                volatility_penalty_weight: 50.0,
+                collateral_penalty_weight: 5.0,
+            },
+            data_layer: DataLayerConfig {
+                cache_expire_seconds: 60,
+                global_rate_limit_per_minute: 60,
+                alpha_vantage: ProviderSettings {
+                    enabled: false,
+                    api_key: String::new(),
+                    priority: 1,
+                    rate_limit_per_minute: 5,
+                },
+                finnhub: ProviderSettings {
+                    enabled: false,
+                    api_key: String::new(),
+                    priority: 2,
+                    rate_limit_per_minute: 60,
+                },
+                twelve_data: ProviderSettings {
+                    enabled: false,
+                    api_key: String::new(),
+                    priority: 3,
+                    rate_limit_per_minute: 8,
+                },
+            },
+            execution: ExecutionConfig {
+                broker_endpoint: String::new(),
+                api_key: String::new(),
             },
         }
     }
@@ -0,0 +1,311 @@
+use crate::{
+    config::ProviderSettings,
+    error::TradingBotError,
+    models::StockData,
+};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use reqwest::Client;
+use std::collections::{HashMap, VecDeque};
+use std::future::Future;
+use std::pin::Pin;
+use std::sync::Mutex;
+
+/// A source of market quotes. Implementations are tried in priority order by
+/// `DataProviderRegistry`, which falls over to the next provider on failure.
+///
+/// Returns a boxed future rather than using `async fn` so the trait stays
+/// object-safe for a `Vec<Box<dyn DataProvider>>` registry.
+pub trait DataProvider: Send + Sync {
+    fn name(&self) -> &'static str;
+    fn priority(&self) -> u32;
+    fn rate_limit_per_minute(&self) -> u32;
+
+    fn fetch_quote<'a>(
+        &'a self,
+        client: &'a Client,
+        ticker: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<StockData, TradingBotError>> + Send + 'a>>;
+}
+
+pub struct AlphaVantageProvider {
+    settings: ProviderSettings,
+}
+
+impl AlphaVantageProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl DataProvider for AlphaVantageProvider {
+    fn name(&self) -> &'static str { "alpha_vantage" }
+    fn priority(&self) -> u32 { self.settings.priority }
+    fn rate_limit_per_minute(&self) -> u32 { self.settings.rate_limit_per_minute }
+
+    fn fetch_quote<'a>(
+        &'a self,
+        client: &'a Client,
+        ticker: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<StockData, TradingBotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = client.get("https://www.alphavantage.co/query")
+                .query(&[
+                    ("function", "GLOBAL_QUOTE"),
+                    ("symbol", ticker),
+                    ("apikey", &self.settings.api_key),
+                ])
+                .send()
+                .await
+                .map_err(|e| TradingBotError::Api(format!("Alpha Vantage request failed: {}", e)))?;
+
+            let data: serde_json::Value = response.json().await.map_err(TradingBotError::Http)?;
+            let quote = data.get("Global Quote")
+                .ok_or_else(|| TradingBotError::Api("Alpha Vantage returned no quote".to_string()))?;
+
+            let current_price: f64 = quote.get("05. price")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| TradingBotError::Api("Alpha Vantage quote missing price".to_string()))?;
+
+            Ok(StockData {
+                ticker: ticker.to_string(),
+                current_price,
+                market_cap: None,
+                volume: None,
+                pe_ratio: None,
+                peg_ratio: None,
+                price_to_sales: None,
+                beta: None,
+                volatility: 0.0,
+                returns: Vec::new(),
+                timestamp: Utc::now(),
+            })
+        })
+    }
+}
+
+pub struct FinnhubProvider {
+    settings: ProviderSettings,
+}
+
+impl FinnhubProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl DataProvider for FinnhubProvider {
+    fn name(&self) -> &'static str { "finnhub" }
+    fn priority(&self) -> u32 { self.settings.priority }
+    fn rate_limit_per_minute(&self) -> u32 { self.settings.rate_limit_per_minute }
+
+    fn fetch_quote<'a>(
+        &'a self,
+        client: &'a Client,
+        ticker: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<StockData, TradingBotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = client.get("https://finnhub.io/api/v1/quote")
+                .query(&[("symbol", ticker), ("token", self.settings.api_key.as_str())])
+                .send()
+                .await
+                .map_err(|e| TradingBotError::Api(format!("Finnhub request failed: {}", e)))?;
+
+            let data: serde_json::Value = response.json().await.map_err(TradingBotError::Http)?;
+            let current_price = data.get("c").and_then(|v| v.as_f64())
+                .ok_or_else(|| TradingBotError::Api("Finnhub quote missing current price".to_string()))?;
+
+            Ok(StockData {
+                ticker: ticker.to_string(),
+                current_price,
+                market_cap: None,
+                volume: None,
+                pe_ratio: None,
+                peg_ratio: None,
+                price_to_sales: None,
+                beta: None,
+                volatility: 0.0,
+                returns: Vec::new(),
+                timestamp: Utc::now(),
+            })
+        })
+    }
+}
+
+pub struct TwelveDataProvider {
+    settings: ProviderSettings,
+}
+
+impl TwelveDataProvider {
+    pub fn new(settings: ProviderSettings) -> Self {
+        Self { settings }
+    }
+}
+
+impl DataProvider for TwelveDataProvider {
+    fn name(&self) -> &'static str { "twelve_data" }
+    fn priority(&self) -> u32 { self.settings.priority }
+    fn rate_limit_per_minute(&self) -> u32 { self.settings.rate_limit_per_minute }
+
+    fn fetch_quote<'a>(
+        &'a self,
+        client: &'a Client,
+        ticker: &'a str,
+    ) -> Pin<Box<dyn Future<Output = Result<StockData, TradingBotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let response = client.get("https://api.twelvedata.com/price")
+                .query(&[("symbol", ticker), ("apikey", self.settings.api_key.as_str())])
+                .send()
+                .await
+                .map_err(|e| TradingBotError::Api(format!("Twelve Data request failed: {}", e)))?;
+
+            let data: serde_json::Value = response.json().await.map_err(TradingBotError::Http)?;
+            let current_price: f64 = data.get("price")
+                .and_then(|v| v.as_str())
+                .and_then(|s| s.parse().ok())
+                .ok_or_else(|| TradingBotError::Api("Twelve Data quote missing price".to_string()))?;
+
+            Ok(StockData {
+                ticker: ticker.to_string(),
+                current_price,
+                market_cap: None,
+                volume: None,
+                pe_ratio: None,
+                peg_ratio: None,
+                price_to_sales: None,
+                beta: None,
+                volatility: 0.0,
+                returns: Vec::new(),
+                timestamp: Utc::now(),
+            })
+        })
+    }
+}
+
+/// Sliding-window rate limiter (not a token bucket): allows at most
+/// `limit_per_minute` calls in any trailing 60 seconds.
+struct RateLimiter {
+    calls: Mutex<VecDeque<DateTime<Utc>>>,
+    limit_per_minute: u32,
+}
+
+impl RateLimiter {
+    fn new(limit_per_minute: u32) -> Self {
+        Self { calls: Mutex::new(VecDeque::new()), limit_per_minute }
+    }
+
+    fn try_acquire(&self) -> bool {
+        let now = Utc::now();
+        let mut calls = self.calls.lock().unwrap();
+        while let Some(&front) = calls.front() {
+            if (now - front).num_seconds() >= 60 {
+                calls.pop_front();
+            } else {
+                break;
+            }
+        }
+
+        if calls.len() as u32 >= self.limit_per_minute {
+            return false;
+        }
+
+        calls.push_back(now);
+        true
+    }
+}
+
+/// Thread-safe quote cache keyed by ticker + interval.
+struct QuoteCache {
+    entries: Mutex<HashMap<(String, String), (StockData, DateTime<Utc>)>>,
+    expire_seconds: i64,
+}
+
+impl QuoteCache {
+    fn new(expire_seconds: i64) -> Self {
+        Self { entries: Mutex::new(HashMap::new()), expire_seconds }
+    }
+
+    fn get(&self, ticker: &str, interval: &str) -> Option<StockData> {
+        let entries = self.entries.lock().unwrap();
+        let (data, fetched_at) = entries.get(&(ticker.to_string(), interval.to_string()))?;
+        if (Utc::now() - *fetched_at).num_seconds() > self.expire_seconds {
+            return None;
+        }
+        Some(data.clone())
+    }
+
+    fn put(&self, ticker: &str, interval: &str, data: StockData) {
+        let mut entries = self.entries.lock().unwrap();
+        entries.insert((ticker.to_string(), interval.to_string()), (data, Utc::now()));
+    }
+}
+
+/// Priority-ordered, cached, rate-limited registry of `DataProvider`s. Queries
+/// the cache first, then fails over through providers from lowest to highest
+/// `priority` value until one succeeds.
+pub struct DataProviderRegistry {
+    providers: Vec<Box<dyn DataProvider>>,
+    rate_limiters: HashMap<&'static str, RateLimiter>,
+    global_rate_limiter: RateLimiter,
+    cache: QuoteCache,
+}
+
+impl DataProviderRegistry {
+    pub fn new(providers: Vec<Box<dyn DataProvider>>, cache_expire_seconds: i64, global_rate_limit_per_minute: u32) -> Self {
+        let mut providers = providers;
+        providers.sort_by_key(|p| p.priority());
+
+        let rate_limiters = providers.iter()
+            .map(|p| (p.name(), RateLimiter::new(p.rate_limit_per_minute())))
+            .collect();
+
+        Self {
+            providers,
+            rate_limiters,
+            global_rate_limiter: RateLimiter::new(global_rate_limit_per_minute),
+            cache: QuoteCache::new(cache_expire_seconds),
+        }
+    }
+
+    /// Returns a cached quote (with its original fetch timestamp preserved so
+    /// staleness is visible) or fails over through providers in priority order.
+    /// Every outbound request - regardless of provider - is gated by a shared
+    /// global rate limiter, on top of each provider's own limit.
+    pub async fn get_quote(&self, client: &Client, ticker: &str, interval: &str) -> Result<StockData, TradingBotError> {
+        if let Some(cached) = self.cache.get(ticker, interval) {
+            info!("💾 Cache hit for {} ({})", ticker, interval);
+            return Ok(cached);
+        }
+
+        let mut last_error = None;
+
+        for provider in &self.providers {
+            if !self.global_rate_limiter.try_acquire() {
+                warn!("Global data-layer rate limit exceeded, skipping {}", provider.name());
+                break;
+            }
+
+            let limiter = &self.rate_limiters[provider.name()];
+            if !limiter.try_acquire() {
+                warn!("Rate limit exceeded for provider {}, skipping", provider.name());
+                continue;
+            }
+
+            match provider.fetch_quote(client, ticker).await {
+                Ok(data) => {
+                    self.cache.put(ticker, interval, data.clone());
+                    return Ok(data);
+                }
+                Err(e) => {
+                    warn!("Provider {} failed for {}: {}", provider.name(), ticker, e);
+                    last_error = Some(e);
+                }
+            }
+        }
+
+        Err(last_error.unwrap_or_else(|| {
+            TradingBotError::Api(format!("No data providers available for {}", ticker))
+        }))
+    }
+}
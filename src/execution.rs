@@ -0,0 +1,243 @@
+use crate::{config::ExecutionConfig, error::TradingBotError, models::PortfolioAllocation};
+use chrono::{DateTime, Utc};
+use log::{info, warn};
+use reqwest::Client;
+use std::future::Future;
+use std::pin::Pin;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OrderSide {
+    Buy,
+    Sell,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeInForce {
+    Day,
+    GoodTilCanceled,
+    ImmediateOrCancel,
+    FillOrKill,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum OrderType {
+    Market,
+    Limit(f64),
+    StopLimit { stop_price: f64, limit_price: f64 },
+    TrailingStopAmount(f64),
+    TrailingStopPercent(f64),
+    LimitIfTouched { trigger_price: f64, limit_price: f64 },
+    MarketIfTouched(f64),
+}
+
+/// A broker-agnostic order. `reference_price` is the price observed when the
+/// order was built (e.g. `PortfolioAllocation.current_price`), used by paper
+/// brokers to simulate whether conditional order types would have filled.
+#[derive(Debug, Clone)]
+pub struct OrderRequest {
+    pub ticker: String,
+    pub side: OrderSide,
+    pub quantity: u32,
+    pub order_type: OrderType,
+    pub time_in_force: TimeInForce,
+    pub reference_price: f64,
+}
+
+impl OrderRequest {
+    /// Maps a target allocation into a market buy order for the shares it calls for.
+    pub fn from_allocation(allocation: &PortfolioAllocation) -> Self {
+        Self {
+            ticker: allocation.ticker.clone(),
+            side: OrderSide::Buy,
+            quantity: allocation.shares_to_buy,
+            order_type: OrderType::Market,
+            time_in_force: TimeInForce::Day,
+            reference_price: allocation.current_price,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExecutionStatus {
+    Filled,
+    PartiallyFilled,
+    Rejected,
+    Pending,
+}
+
+#[derive(Debug, Clone)]
+pub struct ExecutionReport {
+    pub ticker: String,
+    pub filled_quantity: u32,
+    pub avg_fill_price: f64,
+    pub status: ExecutionStatus,
+    pub submitted_at: DateTime<Utc>,
+}
+
+/// A venue that can accept `OrderRequest`s. Uses a boxed future instead of
+/// `async fn` so the trait stays object-safe for a `Box<dyn Broker>`.
+pub trait Broker: Send + Sync {
+    fn name(&self) -> &'static str;
+
+    fn submit_order<'a>(
+        &'a self,
+        order: &'a OrderRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ExecutionReport, TradingBotError>> + Send + 'a>>;
+}
+
+/// Simulates fills against `reference_price` without touching a real venue -
+/// used for dry runs. Market and market-if-touched orders fill at once; limit,
+/// stop-limit and limit-if-touched orders fill at their limit price only when
+/// `reference_price` satisfies them and otherwise stay pending; trailing stops
+/// always stay pending.
+pub struct PaperBroker;
+
+impl PaperBroker {
+    pub fn new() -> Self {
+        Self
+    }
+
+    fn simulate_fill(order: &OrderRequest) -> (ExecutionStatus, f64) {
+        let price = order.reference_price;
+
+        match order.order_type {
+            OrderType::Market | OrderType::MarketIfTouched(_) => (ExecutionStatus::Filled, price),
+            OrderType::Limit(limit_price) => {
+                let crosses = match order.side {
+                    OrderSide::Buy => price <= limit_price,
+                    OrderSide::Sell => price >= limit_price,
+                };
+                if crosses { (ExecutionStatus::Filled, limit_price) } else { (ExecutionStatus::Pending, price) }
+            }
+            OrderType::StopLimit { stop_price, limit_price } | OrderType::LimitIfTouched { trigger_price: stop_price, limit_price } => {
+                let triggered = match order.side {
+                    OrderSide::Buy => price >= stop_price,
+                    OrderSide::Sell => price <= stop_price,
+                };
+                if triggered { (ExecutionStatus::Filled, limit_price) } else { (ExecutionStatus::Pending, price) }
+            }
+            OrderType::TrailingStopAmount(trail) => {
+                let trigger = match order.side {
+                    OrderSide::Buy => price + trail,
+                    OrderSide::Sell => price - trail,
+                };
+                (ExecutionStatus::Pending, trigger)
+            }
+            OrderType::TrailingStopPercent(trail_pct) => {
+                let trigger = match order.side {
+                    OrderSide::Buy => price * (1.0 + trail_pct),
+                    OrderSide::Sell => price * (1.0 - trail_pct),
+                };
+                (ExecutionStatus::Pending, trigger)
+            }
+        }
+    }
+}
+
+impl Default for PaperBroker {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Broker for PaperBroker {
+    fn name(&self) -> &'static str {
+        "paper"
+    }
+
+    fn submit_order<'a>(
+        &'a self,
+        order: &'a OrderRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ExecutionReport, TradingBotError>> + Send + 'a>> {
+        Box::pin(async move {
+            let (status, fill_price) = Self::simulate_fill(order);
+            let filled_quantity = if status == ExecutionStatus::Filled { order.quantity } else { 0 };
+
+            info!("📝 [paper] {:?} {} {} @ ${:.2} -> {:?}", order.side, order.quantity, order.ticker, fill_price, status);
+
+            Ok(ExecutionReport {
+                ticker: order.ticker.clone(),
+                filled_quantity,
+                avg_fill_price: fill_price,
+                status,
+                submitted_at: Utc::now(),
+            })
+        })
+    }
+}
+
+/// Submits orders to a real broker's REST endpoint. Requires
+/// `ExecutionConfig::broker_endpoint`/`api_key` to be set; otherwise every
+/// order is rejected rather than silently no-opping.
+pub struct LiveBroker {
+    config: ExecutionConfig,
+    client: Client,
+}
+
+impl LiveBroker {
+    pub fn new(config: ExecutionConfig) -> Self {
+        Self { config, client: Client::new() }
+    }
+}
+
+impl Broker for LiveBroker {
+    fn name(&self) -> &'static str {
+        "live"
+    }
+
+    fn submit_order<'a>(
+        &'a self,
+        order: &'a OrderRequest,
+    ) -> Pin<Box<dyn Future<Output = Result<ExecutionReport, TradingBotError>> + Send + 'a>> {
+        Box::pin(async move {
+            if self.config.broker_endpoint.is_empty() || self.config.api_key.is_empty() {
+                return Err(TradingBotError::Config("Live broker endpoint/api_key not configured".to_string()));
+            }
+
+            let payload = serde_json::json!({
+                "symbol": order.ticker,
+                "side": format!("{:?}", order.side),
+                "qty": order.quantity,
+                "time_in_force": format!("{:?}", order.time_in_force),
+            });
+
+            let response = self.client.post(&self.config.broker_endpoint)
+                .bearer_auth(&self.config.api_key)
+                .json(&payload)
+                .send()
+                .await
+                .map_err(|e| TradingBotError::Api(format!("Live broker request failed: {}", e)))?;
+
+            if !response.status().is_success() {
+                warn!("Live broker rejected order for {}: {}", order.ticker, response.status());
+                return Ok(ExecutionReport {
+                    ticker: order.ticker.clone(),
+                    filled_quantity: 0,
+                    avg_fill_price: 0.0,
+                    status: ExecutionStatus::Rejected,
+                    submitted_at: Utc::now(),
+                });
+            }
+
+            let body: serde_json::Value = response.json().await.map_err(TradingBotError::Http)?;
+            let filled_quantity = body.get("filled_qty").and_then(|v| v.as_u64()).unwrap_or(0) as u32;
+            let avg_fill_price = body.get("filled_avg_price").and_then(|v| v.as_f64()).unwrap_or(order.reference_price);
+
+            let status = if filled_quantity >= order.quantity {
+                ExecutionStatus::Filled
+            } else if filled_quantity > 0 {
+                ExecutionStatus::PartiallyFilled
+            } else {
+                ExecutionStatus::Pending
+            };
+
+            Ok(ExecutionReport {
+                ticker: order.ticker.clone(),
+                filled_quantity,
+                avg_fill_price,
+                status,
+                submitted_at: Utc::now(),
+            })
+        })
+    }
+}
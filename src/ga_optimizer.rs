@@ -0,0 +1,202 @@
+use crate::{config::GaOptimizerConfig, error::TradingBotError, models::OptionsAnalysis};
+use log::info;
+
+/// A cash-secured-put (or covered-call) candidate the GA can allocate contracts to.
+#[derive(Debug, Clone)]
+pub struct OptionCandidate {
+    pub ticker: String,
+    pub strike: f64,
+    pub premium: f64,
+    pub volatility: f64,
+}
+
+impl OptionCandidate {
+    pub fn from_option(option: &OptionsAnalysis, volatility: f64) -> Self {
+        Self {
+            ticker: option.ticker.clone(),
+            strike: option.strike,
+            premium: (option.bid + option.ask) / 2.0,
+            volatility,
+        }
+    }
+}
+
+#[derive(Debug, Clone)]
+pub struct GaBasket {
+    pub contracts: Vec<u32>,
+    pub total_premium: f64,
+    pub required_collateral: f64,
+    pub collateral_utilization: f64,
+    pub fitness: f64,
+}
+
+const CONTRACTS_PER_LOT: f64 = 100.0;
+const MAX_CONTRACTS_PER_CANDIDATE: u32 = 10;
+const TOURNAMENT_SIZE: usize = 3;
+
+/// Searches for a near-optimal basket of cash-secured option positions under a
+/// fixed collateral budget using a genetic algorithm, rather than greedy
+/// per-ticker selection.
+pub struct GaPortfolioOptimizer {
+    config: GaOptimizerConfig,
+}
+
+impl GaPortfolioOptimizer {
+    pub fn new(config: GaOptimizerConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn optimize(&self, candidates: &[OptionCandidate], budget: f64) -> Result<GaBasket, TradingBotError> {
+        if candidates.is_empty() {
+            return Err(TradingBotError::Calculation("No option candidates to optimize over".to_string()));
+        }
+        if budget <= 0.0 {
+            return Err(TradingBotError::Calculation("Collateral budget must be positive".to_string()));
+        }
+
+        info!("🧬 Running GA over {} candidates for {} generations...", candidates.len(), self.config.generations);
+
+        let mut seed: u64 = 0xD1B54A32D192ED03;
+        let mut population: Vec<Vec<u32>> = (0..self.config.population_size)
+            .map(|_| Self::random_chromosome(candidates.len(), &mut seed))
+            .collect();
+
+        let mut best: Option<GaBasket> = None;
+
+        for generation in 0..self.config.generations {
+            let mut scored: Vec<(Vec<u32>, f64)> = population.iter()
+                .map(|chromosome| (chromosome.clone(), self.fitness(chromosome, candidates, budget)))
+                .collect();
+
+            scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+
+            if best.as_ref().map(|b| scored[0].1 > b.fitness).unwrap_or(true) {
+                best = Some(self.describe(&scored[0].0, candidates, budget, scored[0].1));
+            }
+
+            if generation + 1 == self.config.generations {
+                break;
+            }
+
+            let mut next_generation = Vec::with_capacity(population.len());
+            while next_generation.len() < population.len() {
+                let parent_a = Self::tournament_select(&scored, &mut seed);
+                let parent_b = Self::tournament_select(&scored, &mut seed);
+                let mut child = Self::crossover(parent_a, parent_b, &mut seed);
+                Self::mutate(&mut child, &mut seed);
+                Self::repair(&mut child, candidates, budget, &mut seed);
+                next_generation.push(child);
+            }
+
+            population = next_generation;
+        }
+
+        best.ok_or_else(|| TradingBotError::Calculation("GA failed to produce a basket".to_string()))
+    }
+
+    fn fitness(&self, chromosome: &[u32], candidates: &[OptionCandidate], budget: f64) -> f64 {
+        let total_premium: f64 = chromosome.iter().zip(candidates)
+            .map(|(&count, c)| count as f64 * CONTRACTS_PER_LOT * c.premium)
+            .sum();
+
+        let total_contracts: f64 = chromosome.iter().map(|&c| c as f64).sum();
+        let avg_volatility = if total_contracts > 0.0 {
+            chromosome.iter().zip(candidates)
+                .map(|(&count, c)| count as f64 * c.volatility)
+                .sum::<f64>() / total_contracts
+        } else {
+            0.0
+        };
+
+        let collateral: f64 = chromosome.iter().zip(candidates)
+            .map(|(&count, c)| count as f64 * CONTRACTS_PER_LOT * c.strike)
+            .sum();
+
+        let overage_penalty = if collateral > budget {
+            (collateral - budget) * self.config.collateral_penalty_weight
+        } else {
+            0.0
+        };
+
+        total_premium * self.config.premium_weight
+            - avg_volatility * self.config.volatility_penalty_weight
+            - overage_penalty
+    }
+
+    fn describe(&self, chromosome: &[u32], candidates: &[OptionCandidate], budget: f64, fitness: f64) -> GaBasket {
+        let total_premium: f64 = chromosome.iter().zip(candidates)
+            .map(|(&count, c)| count as f64 * CONTRACTS_PER_LOT * c.premium)
+            .sum();
+
+        let required_collateral: f64 = chromosome.iter().zip(candidates)
+            .map(|(&count, c)| count as f64 * CONTRACTS_PER_LOT * c.strike)
+            .sum();
+
+        GaBasket {
+            contracts: chromosome.to_vec(),
+            total_premium,
+            required_collateral,
+            collateral_utilization: if budget > 0.0 { required_collateral / budget } else { 0.0 },
+            fitness,
+        }
+    }
+
+    fn random_chromosome(len: usize, seed: &mut u64) -> Vec<u32> {
+        (0..len).map(|_| Self::next_u32(seed) % (MAX_CONTRACTS_PER_CANDIDATE + 1)).collect()
+    }
+
+    fn tournament_select<'a>(scored: &'a [(Vec<u32>, f64)], seed: &mut u64) -> &'a [u32] {
+        let mut best_idx = (Self::next_u32(seed) as usize) % scored.len();
+        for _ in 1..TOURNAMENT_SIZE {
+            let idx = (Self::next_u32(seed) as usize) % scored.len();
+            if scored[idx].1 > scored[best_idx].1 {
+                best_idx = idx;
+            }
+        }
+        &scored[best_idx].0
+    }
+
+    fn crossover(parent_a: &[u32], parent_b: &[u32], seed: &mut u64) -> Vec<u32> {
+        if parent_a.len() < 2 {
+            return parent_a.to_vec();
+        }
+        let point = (Self::next_u32(seed) as usize) % parent_a.len();
+        parent_a[..point].iter().chain(parent_b[point..].iter()).copied().collect()
+    }
+
+    fn mutate(chromosome: &mut [u32], seed: &mut u64) {
+        let gene = (Self::next_u32(seed) as usize) % chromosome.len();
+        let nudge_up = Self::next_u32(seed) % 2 == 0;
+
+        if nudge_up {
+            chromosome[gene] = (chromosome[gene] + 1).min(MAX_CONTRACTS_PER_CANDIDATE);
+        } else if chromosome[gene] > 0 {
+            chromosome[gene] -= 1;
+        }
+    }
+
+    /// Randomly decrements positions until the chromosome fits the collateral budget.
+    fn repair(chromosome: &mut [u32], candidates: &[OptionCandidate], budget: f64, seed: &mut u64) {
+        let collateral_of = |chromosome: &[u32]| -> f64 {
+            chromosome.iter().zip(candidates)
+                .map(|(&count, c)| count as f64 * CONTRACTS_PER_LOT * c.strike)
+                .sum()
+        };
+
+        let mut guard = 0;
+        while collateral_of(chromosome) > budget && chromosome.iter().any(|&c| c > 0) && guard < 10_000 {
+            let idx = (Self::next_u32(seed) as usize) % chromosome.len();
+            if chromosome[idx] > 0 {
+                chromosome[idx] -= 1;
+            }
+            guard += 1;
+        }
+    }
+
+    fn next_u32(seed: &mut u64) -> u32 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+        (*seed % (u32::MAX as u64)) as u32
+    }
+}
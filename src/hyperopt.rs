@@ -0,0 +1,220 @@
+use crate::{
+    backtest::{Backtester, BacktestResult, PriceBar},
+    config::TradingConfig,
+    error::TradingBotError,
+    models::PortfolioAllocation,
+};
+use log::info;
+use std::collections::HashMap;
+
+/// A tunable range for a single `TradingConfig` field: `(min, max, step)`, plus
+/// whether it should be sampled as an integer (e.g. `max_positions`).
+#[derive(Debug, Clone, Copy)]
+pub struct ParameterRange {
+    pub min: f64,
+    pub max: f64,
+    pub step: f64,
+    pub is_integer: bool,
+}
+
+#[derive(Debug, Clone, Copy)]
+pub enum Objective {
+    Sharpe,
+    TotalReturn,
+    Calmar,
+}
+
+#[derive(Debug, Clone)]
+pub struct TrialResult {
+    pub config: TradingConfig,
+    pub objective_value: f64,
+    pub sharpe: f64,
+    pub total_return: f64,
+    pub max_drawdown: f64,
+}
+
+pub struct Optimizer {
+    objective: Objective,
+}
+
+/// Maps the `TradingConfig` fields this optimizer is allowed to tune to their
+/// sampling ranges. Keys match the fields `sample_candidate` reads.
+pub fn parameter_space() -> HashMap<&'static str, ParameterRange> {
+    let mut space = HashMap::new();
+    space.insert("default_scaling_factor", ParameterRange { min: 0.1, max: 1.0, step: 0.05, is_integer: false });
+    space.insert("max_allocation_per_position", ParameterRange { min: 0.05, max: 0.5, step: 0.05, is_integer: false });
+    space.insert("min_allocation", ParameterRange { min: 5.0, max: 100.0, step: 5.0, is_integer: false });
+    space.insert("max_positions", ParameterRange { min: 3.0, max: 20.0, step: 1.0, is_integer: true });
+    space
+}
+
+impl Optimizer {
+    pub fn new(objective: Objective) -> Self {
+        Self { objective }
+    }
+
+    pub fn score(&self, result: &BacktestResult, base_config: &TradingConfig) -> f64 {
+        let total_return = if result.starting_equity > 0.0 {
+            (result.final_equity - result.starting_equity) / result.starting_equity
+        } else {
+            0.0
+        };
+
+        match self.objective {
+            Objective::Sharpe => result.realized_sharpe,
+            Objective::TotalReturn => total_return,
+            Objective::Calmar => {
+                let _ = base_config;
+                if result.realized_max_drawdown > 0.0 {
+                    total_return / result.realized_max_drawdown
+                } else {
+                    total_return
+                }
+            }
+        }
+    }
+
+    /// Random search over `ranges`, running `trials` candidate configs and
+    /// scoring each against a fresh backtest of `allocations` over `price_history`.
+    pub fn random_search(
+        &self,
+        base_config: &TradingConfig,
+        ranges: &HashMap<&'static str, ParameterRange>,
+        allocations: &[PortfolioAllocation],
+        price_history: &HashMap<String, Vec<PriceBar>>,
+        trials: usize,
+    ) -> Result<Vec<TrialResult>, TradingBotError> {
+        info!("🔬 Running {} hyperopt trials (random search)...", trials);
+
+        let mut results = Vec::with_capacity(trials);
+        let mut seed: u64 = 0x9E3779B97F4A7C15;
+
+        for _ in 0..trials {
+            let candidate = Self::sample_candidate(base_config, ranges, &mut seed);
+            let trial = self.run_trial(candidate, allocations, price_history)?;
+            results.push(trial);
+        }
+
+        results.sort_by(|a, b| b.objective_value.partial_cmp(&a.objective_value).unwrap());
+        Ok(results)
+    }
+
+    /// Runs `batches` rounds of `trials_per_batch` random trials, narrowing each
+    /// parameter's range around the best-so-far value after every batch.
+    pub fn guided_refinement(
+        &self,
+        base_config: &TradingConfig,
+        mut ranges: HashMap<&'static str, ParameterRange>,
+        allocations: &[PortfolioAllocation],
+        price_history: &HashMap<String, Vec<PriceBar>>,
+        batches: usize,
+        trials_per_batch: usize,
+    ) -> Result<Vec<TrialResult>, TradingBotError> {
+        let mut all_results = Vec::new();
+        let mut best_config = base_config.clone();
+        let mut best_value = f64::NEG_INFINITY;
+
+        for batch in 0..batches {
+            info!("🔬 Hyperopt batch {}/{}", batch + 1, batches);
+            let batch_results = self.random_search(&best_config, &ranges, allocations, price_history, trials_per_batch)?;
+
+            if let Some(best) = batch_results.first() {
+                if best.objective_value > best_value {
+                    best_value = best.objective_value;
+                    best_config = best.config.clone();
+                }
+                Self::narrow_ranges(&mut ranges, &best_config);
+            }
+
+            all_results.extend(batch_results);
+        }
+
+        all_results.sort_by(|a, b| b.objective_value.partial_cmp(&a.objective_value).unwrap());
+        Ok(all_results)
+    }
+
+    fn run_trial(
+        &self,
+        candidate: TradingConfig,
+        allocations: &[PortfolioAllocation],
+        price_history: &HashMap<String, Vec<PriceBar>>,
+    ) -> Result<TrialResult, TradingBotError> {
+        let backtester = Backtester::new(candidate.clone());
+        let result = backtester.run(allocations, price_history)?;
+
+        let total_return = if result.starting_equity > 0.0 {
+            (result.final_equity - result.starting_equity) / result.starting_equity
+        } else {
+            0.0
+        };
+
+        Ok(TrialResult {
+            objective_value: self.score(&result, &candidate),
+            config: candidate,
+            sharpe: result.realized_sharpe,
+            total_return,
+            max_drawdown: result.realized_max_drawdown,
+        })
+    }
+
+    fn sample_candidate(
+        base: &TradingConfig,
+        ranges: &HashMap<&'static str, ParameterRange>,
+        seed: &mut u64,
+    ) -> TradingConfig {
+        let mut candidate = base.clone();
+
+        if let Some(r) = ranges.get("default_scaling_factor") {
+            candidate.default_scaling_factor = Self::sample(r, seed);
+        }
+        if let Some(r) = ranges.get("max_allocation_per_position") {
+            candidate.max_allocation_per_position = Self::sample(r, seed);
+        }
+        if let Some(r) = ranges.get("min_allocation") {
+            candidate.min_allocation = Self::sample(r, seed);
+        }
+        if let Some(r) = ranges.get("max_positions") {
+            candidate.max_positions = Self::sample(r, seed).round() as usize;
+        }
+
+        candidate
+    }
+
+    fn narrow_ranges(ranges: &mut HashMap<&'static str, ParameterRange>, best: &TradingConfig) {
+        let fields: [(&'static str, f64); 4] = [
+            ("default_scaling_factor", best.default_scaling_factor),
+            ("max_allocation_per_position", best.max_allocation_per_position),
+            ("min_allocation", best.min_allocation),
+            ("max_positions", best.max_positions as f64),
+        ];
+
+        for (name, value) in fields {
+            if let Some(r) = ranges.get_mut(name) {
+                let span = (r.max - r.min) * 0.5;
+                r.min = (value - span / 2.0).max(r.min);
+                r.max = (value + span / 2.0).min(r.max);
+                if r.max < r.min {
+                    std::mem::swap(&mut r.max, &mut r.min);
+                }
+            }
+        }
+    }
+
+    /// Deterministic xorshift-based pseudo-random sampler so trials are reproducible.
+    fn sample(range: &ParameterRange, seed: &mut u64) -> f64 {
+        *seed ^= *seed << 13;
+        *seed ^= *seed >> 7;
+        *seed ^= *seed << 17;
+
+        let unit = (*seed as f64 / u64::MAX as f64).clamp(0.0, 1.0);
+        let raw = range.min + unit * (range.max - range.min);
+
+        if range.step > 0.0 {
+            let steps = ((raw - range.min) / range.step).round();
+            let snapped = range.min + steps * range.step;
+            if range.is_integer { snapped.round() } else { snapped }
+        } else {
+            raw
+        }
+    }
+}
@@ -26,30 +26,44 @@ impl KellyAnalyzer {
         &self,
         stock: &StockData,
         kelly: &KellyAnalysis,
+        benchmark_returns: &[f64],
     ) -> Result<UnifiedRiskRewardMetric, TradingBotError> {
         info!("🧮 Calculating unified metric for {}", stock.ticker);
         
         // Calculate Sortino ratio
         let sortino_ratio = Self::calculate_sortino_ratio(&stock.returns, 0.0, self.config.risk_free_rate)?;
-        
+
         // Calculate Calmar ratio
         let calmar_ratio = Self::calculate_calmar_ratio(&stock.returns, self.config.lookback_days as f64)?;
-        
-        // Calculate unified score (weighted average)
-        let unified_score = (kelly.confidence_weighted_kelly * 0.4 + 
-                           sortino_ratio * 0.3 + 
-                           calmar_ratio * 0.3).max(0.0).min(1.0);
-        
+
+        // Calculate tail-risk measures
+        let value_at_risk = Self::calculate_historical_var(&stock.returns, self.config.confidence_level);
+        let conditional_value_at_risk = Self::calculate_historical_cvar(&stock.returns, self.config.confidence_level);
+
+        // Calculate unified score (weighted average), penalized for fat left tails
+        let var_penalty = (value_at_risk * 2.0).min(1.0);
+        let unified_score = (kelly.confidence_weighted_kelly * 0.4 +
+                           sortino_ratio * 0.3 +
+                           calmar_ratio * 0.3 -
+                           var_penalty * 0.2).max(0.0).min(1.0);
+
         // Calculate average return
         let avg_return = if !stock.returns.is_empty() {
             stock.returns.iter().sum::<f64>() / stock.returns.len() as f64
         } else {
             0.0
         };
-        
+
         // Calculate risk-adjusted Kelly
         let risk_adjusted_kelly = kelly.confidence_weighted_kelly * unified_score;
-        
+
+        // Calculate benchmark-relative metrics
+        let (beta, alpha, tracking_error) = Self::calculate_benchmark_metrics(
+            &stock.returns,
+            benchmark_returns,
+            self.config.risk_free_rate,
+        );
+
         Ok(UnifiedRiskRewardMetric {
             ticker: stock.ticker.clone(),
             unified_score,
@@ -67,8 +81,81 @@ impl KellyAnalyzer {
             win_probability: kelly.win_probability,
             confidence_factor: kelly.confidence_factor,
             sample_size: stock.returns.len(),
+            value_at_risk,
+            conditional_value_at_risk,
+            beta,
+            alpha,
+            tracking_error,
         })
     }
+
+    /// Computes beta, Jensen's alpha, and annualized tracking error against a
+    /// benchmark return series. Series are aligned to the shorter of the two;
+    /// fewer than two aligned points yields all zeros, and zero benchmark
+    /// variance yields a beta of zero (alpha and tracking error are still computed).
+    fn calculate_benchmark_metrics(returns: &[f64], benchmark_returns: &[f64], risk_free_rate: f64) -> (f64, f64, f64) {
+        let n = returns.len().min(benchmark_returns.len());
+        if n < 2 {
+            return (0.0, 0.0, 0.0);
+        }
+
+        let returns = &returns[..n];
+        let benchmark_returns = &benchmark_returns[..n];
+
+        let mean_r = returns.iter().sum::<f64>() / n as f64;
+        let mean_b = benchmark_returns.iter().sum::<f64>() / n as f64;
+
+        let covariance = returns.iter().zip(benchmark_returns.iter())
+            .map(|(r, b)| (r - mean_r) * (b - mean_b))
+            .sum::<f64>() / n as f64;
+
+        let benchmark_variance = benchmark_returns.iter()
+            .map(|b| (b - mean_b).powi(2))
+            .sum::<f64>() / n as f64;
+
+        let beta = if benchmark_variance > 0.0 { covariance / benchmark_variance } else { 0.0 };
+
+        let daily_rf = risk_free_rate / 252.0;
+        let alpha = (mean_r - daily_rf) - beta * (mean_b - daily_rf);
+
+        let diffs: Vec<f64> = returns.iter().zip(benchmark_returns.iter()).map(|(r, b)| r - b).collect();
+        let mean_diff = diffs.iter().sum::<f64>() / n as f64;
+        let diff_variance = diffs.iter().map(|d| (d - mean_diff).powi(2)).sum::<f64>() / n as f64;
+        let tracking_error = diff_variance.sqrt() * (252.0f64).sqrt();
+
+        (beta, alpha, tracking_error)
+    }
+
+    /// Historical VaR: the loss at the `(1 - confidence)` quantile of the
+    /// return distribution, reported as a positive loss magnitude.
+    fn calculate_historical_var(returns: &[f64], confidence: f64) -> f64 {
+        if returns.is_empty() {
+            return 0.0;
+        }
+
+        let mut sorted = returns.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let index = (((1.0 - confidence) * sorted.len() as f64).floor() as usize).min(sorted.len() - 1);
+        (-sorted[index]).max(0.0)
+    }
+
+    /// Historical CVaR: the mean of all returns at or below the VaR threshold,
+    /// capturing how bad the worst cases are on average.
+    fn calculate_historical_cvar(returns: &[f64], confidence: f64) -> f64 {
+        if returns.is_empty() {
+            return 0.0;
+        }
+
+        let mut sorted = returns.to_vec();
+        sorted.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+        let index = (((1.0 - confidence) * sorted.len() as f64).floor() as usize).min(sorted.len() - 1);
+        let tail = &sorted[..=index];
+        let tail_mean = tail.iter().sum::<f64>() / tail.len() as f64;
+
+        (-tail_mean).max(0.0)
+    }
     
     fn calculate_sortino_ratio(returns: &[f64], target_return: f64, risk_free_rate: f64) -> Result<f64, TradingBotError> {
         if returns.is_empty() {
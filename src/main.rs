@@ -49,6 +49,229 @@ enum Commands {
     
     /// Test Python bridge functionality
     TestPython,
+
+    /// Stream real-time market data for a set of symbols
+    Stream {
+        #[arg(short, long, value_delimiter = ',')]
+        symbols: Vec<String>,
+
+        /// "stock" or "crypto"
+        #[arg(short, long, default_value = "stock")]
+        market: String,
+    },
+
+    /// Run the complete analysis and submit trades for the resulting allocation
+    Trade {
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        #[arg(short, long, default_value = "0.5")]
+        scaling_factor: f64,
+
+        /// Route orders to the paper broker instead of the configured live broker
+        #[arg(long, default_value = "true")]
+        dry_run: bool,
+    },
+
+    /// Replicate a linear liquidity ladder of limit orders across a price range
+    Replicate {
+        #[arg(short, long)]
+        ticker: String,
+
+        #[arg(long)]
+        low: f64,
+
+        #[arg(long)]
+        high: f64,
+
+        #[arg(short, long, default_value = "5")]
+        rungs: usize,
+
+        /// "flat", "linear", or "xyk"
+        #[arg(short, long, default_value = "flat")]
+        mode: String,
+
+        #[arg(short, long, default_value = "100")]
+        quantity: u32,
+
+        /// Route orders to the paper broker instead of the configured live broker
+        #[arg(long, default_value = "true")]
+        dry_run: bool,
+    },
+
+    /// Run the complete analysis and rebalance its allocation to explicit targets
+    Rebalance {
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        #[arg(short, long, default_value = "0.5")]
+        scaling_factor: f64,
+
+        /// "TICKER:WEIGHT" pairs, e.g. "AAPL:0.3"
+        #[arg(short, long, value_delimiter = ',')]
+        targets: Vec<String>,
+
+        #[arg(long, default_value = "25")]
+        min_trade_volume: f64,
+    },
+
+    /// Backtest an EWMA trend-following rule for a single ticker against buy-and-hold
+    Backtest {
+        #[arg(short, long)]
+        ticker: String,
+
+        /// Start of the backtest window, RFC3339 (e.g. "2024-01-01T00:00:00Z")
+        #[arg(long)]
+        start: String,
+
+        /// End of the backtest window, RFC3339
+        #[arg(long)]
+        end: String,
+
+        #[arg(long, default_value = "1d")]
+        timeframe: String,
+    },
+
+    /// Rank a candidate universe by trailing momentum and keep the top N
+    RankMomentum {
+        #[arg(short, long, value_delimiter = ',')]
+        universe: Vec<String>,
+
+        #[arg(short, long, default_value = "252")]
+        lookback_days: u32,
+
+        #[arg(short, long, default_value = "10")]
+        top_n: usize,
+
+        #[arg(long, default_value = "0.0")]
+        min_trend: f64,
+    },
+
+    /// Sweep Kelly scaling-factor candidates through a backtest and pick the winner
+    OptimizeScaling {
+        #[arg(short, long, value_delimiter = ',')]
+        tickers: Vec<String>,
+
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        /// Comma-separated scaling-factor candidates, e.g. "0.25,0.5,0.75,1.0"
+        #[arg(short, long, value_delimiter = ',')]
+        candidates: Vec<f64>,
+
+        #[arg(long)]
+        start: String,
+
+        #[arg(long)]
+        end: String,
+    },
+
+    /// Rebalance a share-based holdings ledger toward target weights via the three-pass cash/bounds engine
+    RebalanceHoldings {
+        /// "TICKER:SHARES:PRICE" entries, e.g. "AAPL:10:200.0"
+        #[arg(short, long, value_delimiter = ',')]
+        holdings: Vec<String>,
+
+        #[arg(long, default_value = "0")]
+        cash: f64,
+
+        /// "TICKER:WEIGHT" pairs, e.g. "AAPL:0.3"
+        #[arg(short, long, value_delimiter = ',')]
+        targets: Vec<String>,
+
+        #[arg(long, default_value = "0.0")]
+        min_weight: f64,
+
+        #[arg(long, default_value = "0.35")]
+        max_weight: f64,
+
+        #[arg(long, default_value = "0")]
+        min_cash_reserve: f64,
+    },
+
+    /// Search for a near-optimal basket of cash-secured option positions under a collateral budget
+    OptimizeOptionsBasket {
+        #[arg(short, long)]
+        ticker: String,
+
+        #[arg(short, long)]
+        budget: f64,
+    },
+
+    /// Run the complete analysis and compute DCA-style scale-in/scale-out deltas from held positions
+    AdjustPositions {
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        #[arg(short, long, default_value = "0.5")]
+        scaling_factor: f64,
+
+        /// "TICKER:DOLLAR_VALUE:KELLY_FRACTION" entries, e.g. "AAPL:500:0.2"
+        #[arg(long, value_delimiter = ',')]
+        held: Vec<String>,
+    },
+
+    /// Sweep random-search hyperparameter trials against a backtest and pick the winner
+    OptimizeHyperparameters {
+        #[arg(short, long, value_delimiter = ',')]
+        tickers: Vec<String>,
+
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        /// "sharpe", "total_return", or "calmar"
+        #[arg(short, long, default_value = "sharpe")]
+        objective: String,
+
+        #[arg(short, long, default_value = "3")]
+        batches: usize,
+
+        #[arg(long, default_value = "20")]
+        trials_per_batch: usize,
+
+        #[arg(long)]
+        start: String,
+
+        #[arg(long)]
+        end: String,
+    },
+
+    /// Replay a portfolio allocation through its historical bars and print a trade-stats report
+    BacktestReport {
+        #[arg(short, long, value_delimiter = ',')]
+        tickers: Vec<String>,
+
+        #[arg(short, long, default_value = "1000")]
+        portfolio_value: f64,
+
+        #[arg(short, long, default_value = "0.5")]
+        scaling_factor: f64,
+
+        /// Re-target weights every N days instead of holding the initial allocation fixed
+        #[arg(long)]
+        rebalance_every_days: Option<usize>,
+
+        #[arg(long)]
+        start: String,
+
+        #[arg(long)]
+        end: String,
+    },
+
+    /// Compute realized IRR, time-weighted return, and per-position P&L for a running portfolio
+    Performance {
+        /// "TICKER:ENTRY_DATE:ENTRY_PRICE:SHARES:CURRENT_PRICE" entries (RFC3339 dates), e.g. "AAPL:2024-01-01T00:00:00Z:150.0:10:200.0"
+        #[arg(short, long, value_delimiter = ',')]
+        positions: Vec<String>,
+
+        /// "DATE:AMOUNT" entries (RFC3339 dates); negative = deposit/buy, positive = withdrawal/sell
+        #[arg(short, long, value_delimiter = ',')]
+        cash_flows: Vec<String>,
+
+        /// As-of date for mark-to-market, RFC3339; defaults to now if omitted
+        #[arg(long)]
+        as_of: Option<String>,
+    },
 }
 
 #[tokio::main]
@@ -100,8 +323,224 @@ async fn main() -> Result<(), TradingBotError> {
             info!("🧪 Testing Python bridge...");
             trading_bot.test_python_bridge().await?;
         }
+
+        Commands::Stream { symbols, market } => {
+            let market = match market.as_str() {
+                "crypto" => trading_bot::streaming::Market::Crypto,
+                _ => trading_bot::streaming::Market::Stock,
+            };
+            info!("📡 Streaming market data for {:?}...", symbols);
+            trading_bot.stream_market_data(symbols, market).await?;
+        }
+
+        Commands::Trade { portfolio_value, scaling_factor, dry_run } => {
+            info!("💸 Running analysis and submitting trades (dry_run={})...", dry_run);
+            let result = trading_bot.run_complete_analysis(portfolio_value, scaling_factor).await?;
+            if let Some(summary) = &result.portfolio_summary {
+                let reports = trading_bot.execute_trades(summary, dry_run).await?;
+                println!("Execution Reports: {:#?}", reports);
+            } else {
+                info!("No portfolio allocation was produced; nothing to trade");
+            }
+        }
+
+        Commands::Replicate { ticker, low, high, rungs, mode, quantity, dry_run } => {
+            let mode = match mode.as_str() {
+                "linear" => trading_bot::strategies::LadderMode::Linear,
+                "xyk" => trading_bot::strategies::LadderMode::Xyk,
+                _ => trading_bot::strategies::LadderMode::Flat,
+            };
+            info!("🪜 Replicating liquidity ladder for {}...", ticker);
+            let reports = trading_bot.replicate_ladder(
+                ticker, low, high, rungs, mode, quantity,
+                trading_bot::execution::OrderSide::Buy, dry_run,
+            ).await?;
+            println!("Execution Reports: {:#?}", reports);
+        }
+
+        Commands::Rebalance { portfolio_value, scaling_factor, targets, min_trade_volume } => {
+            info!("⚖️ Running analysis and rebalancing to explicit targets...");
+            let result = trading_bot.run_complete_analysis(portfolio_value, scaling_factor).await?;
+            if let Some(summary) = result.portfolio_summary {
+                let parsed_targets = targets.iter().filter_map(|t| {
+                    let (ticker, weight) = t.split_once(':')?;
+                    Some((ticker.to_string(), weight.parse::<f64>().ok()?))
+                }).collect();
+                let rebalanced = trading_bot.rebalance_portfolio_to_targets(summary, parsed_targets, min_trade_volume)?;
+                println!("Rebalanced Portfolio: {:#?}", rebalanced);
+            } else {
+                info!("No portfolio allocation was produced; nothing to rebalance");
+            }
+        }
+
+        Commands::Backtest { ticker, start, end, timeframe } => {
+            let start = start.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --start: {}", e)))?;
+            let end = end.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --end: {}", e)))?;
+            info!("🧪 Backtesting trend strategy for {}", ticker);
+            let result = trading_bot.backtest_strategy(&ticker, start, end, &timeframe).await?;
+            println!("Strategy Backtest Result: {:#?}", result);
+        }
+
+        Commands::RankMomentum { universe, lookback_days, top_n, min_trend } => {
+            info!("🏆 Ranking {} candidates by momentum...", universe.len());
+            let result = trading_bot.rank_by_momentum(universe, lookback_days, top_n, min_trend).await?;
+            println!("Momentum-Ranked Stocks: {:#?}", result);
+        }
+
+        Commands::OptimizeScaling { tickers, portfolio_value, candidates, start, end } => {
+            let start = start.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --start: {}", e)))?;
+            let end = end.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --end: {}", e)))?;
+
+            info!("🔬 Optimizing scaling factor over {} candidates...", candidates.len());
+            let mut stocks_data = Vec::new();
+            for ticker in &tickers {
+                stocks_data.push(trading_bot.analyze_stock_potential(ticker).await?);
+            }
+            let result = trading_bot.optimize_scaling_factor(stocks_data, portfolio_value, candidates, start, end).await?;
+            println!("Scaling Optimization Result: {:#?}", result);
+        }
+
+        Commands::RebalanceHoldings { holdings, cash, targets, min_weight, max_weight, min_cash_reserve } => {
+            let parsed_holdings: Vec<trading_bot::rebalance::Holding> = holdings.iter()
+                .filter_map(|h| {
+                    let fields: Vec<&str> = h.split(':').collect();
+                    if fields.len() != 3 {
+                        return None;
+                    }
+                    Some(trading_bot::rebalance::Holding {
+                        ticker: fields[0].to_string(),
+                        shares: fields[1].parse().ok()?,
+                        price: fields[2].parse().ok()?,
+                    })
+                })
+                .collect();
+            let parsed_targets: Vec<(String, f64)> = targets.iter()
+                .filter_map(|t| {
+                    let (ticker, weight) = t.split_once(':')?;
+                    Some((ticker.to_string(), weight.parse::<f64>().ok()?))
+                })
+                .collect();
+
+            info!("⚖️ Rebalancing {} holdings against {} targets...", parsed_holdings.len(), parsed_targets.len());
+            let result = trading_bot.rebalance_holdings(&parsed_holdings, cash, &parsed_targets, min_weight, max_weight, min_cash_reserve)?;
+            println!("Rebalance Result: {:#?}", result);
+        }
+
+        Commands::OptimizeOptionsBasket { ticker, budget } => {
+            info!("🧬 Optimizing options basket for {}", ticker);
+            let options = trading_bot.analyze_options(&ticker).await?;
+            let basket = trading_bot.optimize_options_basket(&options, budget)?;
+            println!("GA Basket Result: {:#?}", basket);
+        }
+
+        Commands::AdjustPositions { portfolio_value, scaling_factor, held } => {
+            info!("📐 Running analysis and computing position adjustments...");
+            let result = trading_bot.run_complete_analysis(portfolio_value, scaling_factor).await?;
+            if let Some(summary) = &result.portfolio_summary {
+                let held_positions: Vec<trading_bot::portfolio::HeldPosition> = held.iter()
+                    .filter_map(|h| {
+                        let fields: Vec<&str> = h.split(':').collect();
+                        if fields.len() != 3 {
+                            return None;
+                        }
+                        Some(trading_bot::portfolio::HeldPosition {
+                            ticker: fields[0].to_string(),
+                            dollar_value: fields[1].parse().ok()?,
+                            kelly_fraction: fields[2].parse().ok()?,
+                        })
+                    })
+                    .collect();
+                let adjustments = trading_bot.adjust_positions(
+                    &held_positions, &summary.allocations, summary.cash_remaining, summary.concentration_risk,
+                )?;
+                println!("Position Adjustments: {:#?}", adjustments);
+            } else {
+                info!("No portfolio allocation was produced; nothing to adjust");
+            }
+        }
+
+        Commands::OptimizeHyperparameters { tickers, portfolio_value, objective, batches, trials_per_batch, start, end } => {
+            let start = start.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --start: {}", e)))?;
+            let end = end.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --end: {}", e)))?;
+            let objective = match objective.as_str() {
+                "total_return" => trading_bot::hyperopt::Objective::TotalReturn,
+                "calmar" => trading_bot::hyperopt::Objective::Calmar,
+                _ => trading_bot::hyperopt::Objective::Sharpe,
+            };
+
+            info!("🔬 Tuning hyperparameters for {} tickers...", tickers.len());
+            let mut stocks_data = Vec::new();
+            for ticker in &tickers {
+                stocks_data.push(trading_bot.analyze_stock_potential(ticker).await?);
+            }
+            let results = trading_bot.optimize_hyperparameters(
+                stocks_data, portfolio_value, objective, batches, trials_per_batch, start, end,
+            ).await?;
+            println!("Hyperopt Trial Results: {:#?}", results);
+        }
+
+        Commands::BacktestReport { tickers, portfolio_value, scaling_factor, rebalance_every_days, start, end } => {
+            let start = start.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --start: {}", e)))?;
+            let end = end.parse::<chrono::DateTime<chrono::Utc>>()
+                .map_err(|e| TradingBotError::Config(format!("Invalid --end: {}", e)))?;
+
+            info!("🧪 Running backtest report for {} tickers...", tickers.len());
+            let mut stocks_data = Vec::new();
+            for ticker in &tickers {
+                stocks_data.push(trading_bot.analyze_stock_potential(ticker).await?);
+            }
+            let report = trading_bot.run_backtest_report(
+                stocks_data, portfolio_value, scaling_factor, rebalance_every_days, start, end,
+            ).await?;
+            println!("Backtest Trade-Stats Report: {:#?}", report);
+        }
+
+        Commands::Performance { positions, cash_flows, as_of } => {
+            let parsed_positions: Vec<trading_bot::performance::Position> = positions.iter()
+                .filter_map(|p| {
+                    // The RFC3339 entry date itself contains ':', so take the
+                    // three numeric fields from the right and the ticker from the left.
+                    let mut fields = p.rsplitn(4, ':');
+                    let current_price: f64 = fields.next()?.parse().ok()?;
+                    let shares: f64 = fields.next()?.parse().ok()?;
+                    let entry_price: f64 = fields.next()?.parse().ok()?;
+                    let (ticker, entry_date) = fields.next()?.split_once(':')?;
+                    Some(trading_bot::performance::Position {
+                        ticker: ticker.to_string(),
+                        entry_date: entry_date.parse().ok()?,
+                        entry_price,
+                        shares,
+                        current_price,
+                    })
+                })
+                .collect();
+
+            let parsed_cash_flows: Vec<(chrono::DateTime<chrono::Utc>, f64)> = cash_flows.iter()
+                .filter_map(|c| {
+                    let (date, amount) = c.rsplit_once(':')?;
+                    Some((date.parse().ok()?, amount.parse().ok()?))
+                })
+                .collect();
+
+            let as_of = match as_of {
+                Some(s) => s.parse::<chrono::DateTime<chrono::Utc>>()
+                    .map_err(|e| TradingBotError::Config(format!("Invalid --as-of: {}", e)))?,
+                None => chrono::Utc::now(),
+            };
+
+            info!("📐 Computing portfolio performance for {} positions...", parsed_positions.len());
+            let result = trading_bot.portfolio_performance(parsed_positions, parsed_cash_flows, as_of)?;
+            println!("Performance Analysis: {:#?}", result);
+        }
     }
-    
+
     info!("✅ Trading bot completed successfully");
     Ok(())
 }
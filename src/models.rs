@@ -31,6 +31,12 @@ pub struct KellyAnalysis {
     pub confidence_factor: f64,
 }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum OptionType {
+    Call,
+    Put,
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct OptionsAnalysis {
     pub ticker: String,
@@ -46,6 +52,9 @@ pub struct OptionsAnalysis {
     pub score: f64,
     pub reasons: Vec<String>,
     pub greeks: Option<OptionGreeks>,
+    pub implied_volatility: Option<f64>,
+    pub option_type: OptionType,
+    pub contract_style: crate::pricing::ContractStyle,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -54,6 +63,7 @@ pub struct OptionGreeks {
     pub gamma: f64,
     pub theta: f64,
     pub vega: f64,
+    pub rho: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -71,6 +81,9 @@ pub struct PortfolioAllocation {
     pub sharpe_ratio: f64,
     pub doubling_score: f64,
     pub reasons: Vec<String>,
+    /// Shares to buy (positive) or sell (negative) to reach `dollar_allocation`,
+    /// populated by rebalancing passes; zero for a fresh allocation.
+    pub delta_shares: i64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -85,6 +98,10 @@ pub struct PortfolioSummary {
     pub max_drawdown_estimate: f64,
     pub number_of_positions: usize,
     pub concentration_risk: f64,
+    /// Weighted average of individual volatilities divided by the true
+    /// (covariance-based) portfolio volatility; >1.0 means diversification is
+    /// reducing risk below the naive weighted-sum estimate.
+    pub diversification_ratio: f64,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -134,6 +151,58 @@ pub struct UnifiedRiskRewardMetric {
     pub win_probability: f64,
     pub confidence_factor: f64,
     pub sample_size: usize,
+    pub value_at_risk: f64,
+    pub conditional_value_at_risk: f64,
+    /// Sensitivity of returns to the benchmark index (1.0 = moves with the market).
+    pub beta: f64,
+    /// Jensen's alpha: realized return in excess of what beta/CAPM would predict.
+    pub alpha: f64,
+    /// Annualized standard deviation of the return difference vs the benchmark.
+    pub tracking_error: f64,
+}
+
+/// A single round-trip produced by `PythonBridge::backtest_strategy`'s trend rule.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyTrade {
+    pub entry_date: DateTime<Utc>,
+    pub exit_date: DateTime<Utc>,
+    pub entry_price: f64,
+    pub exit_price: f64,
+    pub return_pct: f64,
+}
+
+/// Result of replaying an EWMA trend-following strategy bar-by-bar against a
+/// buy-and-hold benchmark, via `PythonBridge::backtest_strategy`. Distinct from
+/// `backtest::BacktestResult`, which replays a fixed multi-asset allocation
+/// rather than a single-ticker signal-driven strategy.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct StrategyBacktestResult {
+    pub equity_curve: Vec<f64>,
+    pub trades: Vec<StrategyTrade>,
+    pub total_return: f64,
+    pub max_drawdown: f64,
+    pub sharpe_ratio: f64,
+    pub benchmark_return: f64,
+    pub win_rate: f64,
+    pub avg_trade_duration_days: f64,
+}
+
+/// One candidate's backtested performance, produced by
+/// `TradingBot::optimize_scaling_factor`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingFactorTrial {
+    pub scaling_factor: f64,
+    pub total_return: f64,
+    pub sharpe_ratio: f64,
+    pub max_drawdown: f64,
+}
+
+/// Result of sweeping `scaling_factor` candidates through a backtest,
+/// scored by Sharpe ratio with lower max drawdown breaking ties.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScalingOptimizationResult {
+    pub best_scaling_factor: f64,
+    pub trials: Vec<ScalingFactorTrial>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
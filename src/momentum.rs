@@ -0,0 +1,79 @@
+use crate::config::TradingConfig;
+use crate::models::StockData;
+
+/// Ranks a candidate universe by price momentum so `run_complete_analysis` can
+/// analyze a rotating set of names instead of a fixed ticker list.
+pub struct MomentumSelector {
+    config: TradingConfig,
+}
+
+impl MomentumSelector {
+    pub fn new(config: TradingConfig) -> Self {
+        Self { config }
+    }
+
+    /// Total return over the last `momentum_days` daily returns: the product of
+    /// `(1 + r)` across the window, minus one.
+    pub fn momentum(&self, returns: &[f64]) -> f64 {
+        let window_size = (self.config.momentum_days as usize).min(returns.len());
+        if window_size == 0 {
+            return 0.0;
+        }
+
+        let window = &returns[returns.len() - window_size..];
+        window.iter().fold(1.0, |acc, r| acc * (1.0 + r)) - 1.0
+    }
+
+    /// Ranks `candidates` (ticker, daily returns) by momentum descending, drops
+    /// anything below `trend`, and keeps the top `num_stocks`.
+    pub fn rank(&self, candidates: Vec<(String, Vec<f64>)>) -> Vec<String> {
+        let mut scored: Vec<(String, f64)> = candidates.into_iter()
+            .map(|(ticker, returns)| {
+                let momentum = self.momentum(&returns);
+                (ticker, momentum)
+            })
+            .filter(|(_, momentum)| *momentum >= self.config.trend)
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        scored.into_iter()
+            .take(self.config.num_stocks)
+            .map(|(ticker, _)| ticker)
+            .collect()
+    }
+
+    /// Like `rank`, but for universe selection rather than the fixed
+    /// `momentum_days`/`num_stocks`/`trend` config: takes explicit
+    /// `lookback_days`/`top_n`/`min_trend` and keeps the full `StockData`
+    /// instead of just the ticker, so callers can feed the result straight
+    /// into `calculate_portfolio_allocation`.
+    pub fn rank_by_momentum(
+        &self,
+        candidates: Vec<StockData>,
+        lookback_days: u32,
+        top_n: usize,
+        min_trend: f64,
+    ) -> Vec<StockData> {
+        let window_size = lookback_days as usize;
+
+        let mut scored: Vec<(StockData, f64)> = candidates.into_iter()
+            .map(|stock| {
+                let window = if window_size == 0 || stock.returns.is_empty() {
+                    &stock.returns[..0]
+                } else {
+                    let start = stock.returns.len().saturating_sub(window_size);
+                    &stock.returns[start..]
+                };
+                let momentum = window.iter().fold(1.0, |acc, r| acc * (1.0 + r)) - 1.0;
+                (stock, momentum)
+            })
+            .filter(|(_, momentum)| *momentum >= min_trend)
+            .collect();
+
+        scored.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
+        scored.into_iter()
+            .take(top_n)
+            .map(|(stock, _)| stock)
+            .collect()
+    }
+}
@@ -0,0 +1,134 @@
+use crate::{
+    config::NotificationConfig,
+    error::TradingBotError,
+    models::PortfolioSummary,
+    utils::{format_currency, format_percentage},
+};
+use log::{info, warn};
+use reqwest::Client;
+use serde_json::json;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Severity {
+    Info,
+    Warning,
+    Error,
+}
+
+impl Severity {
+    fn parse(value: &str) -> Severity {
+        match value.to_lowercase().as_str() {
+            "warning" => Severity::Warning,
+            "error" => Severity::Error,
+            _ => Severity::Info,
+        }
+    }
+}
+
+/// Structured events the bot can notify external sinks about.
+pub enum NotifyEvent {
+    PortfolioSummaryReady(PortfolioSummary),
+    RiskThresholdBreached { ticker: String, concentration_risk: f64 },
+    Error(String),
+}
+
+impl NotifyEvent {
+    fn severity(&self) -> Severity {
+        match self {
+            NotifyEvent::PortfolioSummaryReady(_) => Severity::Info,
+            NotifyEvent::RiskThresholdBreached { .. } => Severity::Warning,
+            NotifyEvent::Error(_) => Severity::Error,
+        }
+    }
+
+    fn event_key(&self) -> &'static str {
+        match self {
+            NotifyEvent::PortfolioSummaryReady(_) => "portfolio_summary",
+            NotifyEvent::RiskThresholdBreached { .. } => "risk_threshold",
+            NotifyEvent::Error(_) => "error",
+        }
+    }
+}
+
+/// Dispatches `NotifyEvent`s to a generic JSON webhook. The payload is the
+/// event's own JSON object; it carries no Discord `content` or Slack `text` field.
+pub struct WebhookNotifier {
+    config: NotificationConfig,
+    client: Client,
+}
+
+impl WebhookNotifier {
+    pub fn new(config: NotificationConfig) -> Self {
+        let client = Client::builder()
+            .timeout(std::time::Duration::from_secs(10))
+            .build()
+            .unwrap_or_default();
+
+        Self { config, client }
+    }
+
+    /// Sends `event` to the configured webhook. Failures are surfaced as
+    /// `TradingBotError::Api` but are never meant to abort the caller's main flow.
+    pub async fn send(&self, event: NotifyEvent) -> Result<(), TradingBotError> {
+        if !self.config.enabled {
+            return Ok(());
+        }
+
+        if event.severity() < Severity::parse(&self.config.min_severity) {
+            return Ok(());
+        }
+
+        if !self.config.events.get(event.event_key()).copied().unwrap_or(true) {
+            return Ok(());
+        }
+
+        let payload = Self::build_payload(&event);
+
+        let response = self.client
+            .post(&self.config.webhook_url)
+            .json(&payload)
+            .send()
+            .await
+            .map_err(|e| TradingBotError::Api(format!("Failed to send notification: {}", e)))?;
+
+        if !response.status().is_success() {
+            return Err(TradingBotError::Api(format!(
+                "Notification webhook returned status: {}", response.status()
+            )));
+        }
+
+        info!("📣 Sent {} notification", event.event_key());
+        Ok(())
+    }
+
+    /// Sends `event`, logging (rather than propagating) any failure so a
+    /// notification outage never interrupts the main analysis flow.
+    pub async fn send_best_effort(&self, event: NotifyEvent) {
+        if let Err(e) = self.send(event).await {
+            warn!("⚠️ Notification delivery failed: {}", e);
+        }
+    }
+
+    fn build_payload(event: &NotifyEvent) -> serde_json::Value {
+        match event {
+            NotifyEvent::PortfolioSummaryReady(summary) => json!({
+                "event": "portfolio_summary",
+                "total_allocated": format_currency(summary.total_allocated),
+                "cash_remaining": format_currency(summary.cash_remaining),
+                "expected_return": format_percentage(summary.expected_return),
+                "portfolio_sharpe": summary.portfolio_sharpe,
+                "max_drawdown_estimate": format_percentage(summary.max_drawdown_estimate),
+                "number_of_positions": summary.number_of_positions,
+            }),
+            NotifyEvent::RiskThresholdBreached { ticker, concentration_risk } => json!({
+                "event": "risk_threshold",
+                "ticker": ticker,
+                "concentration_risk": format_percentage(*concentration_risk),
+            }),
+            NotifyEvent::Error(message) => json!({
+                "event": "error",
+                "message": message,
+            }),
+        }
+    }
+}
@@ -2,10 +2,15 @@ use crate::{
     models::*,
     error::TradingBotError,
     config::TradingConfig,
+    pricing::BsmPricer,
 };
 use log::info;
 use statrs::distribution::{Normal, ContinuousCDF, Continuous};
 
+/// Relative model-vs-market price gap above which an option is flagged as
+/// mispriced rather than just within normal bid/ask noise.
+const MISPRICING_THRESHOLD: f64 = 0.15;
+
 pub struct OptionsAnalyzer {
     config: TradingConfig,
 }
@@ -17,38 +22,201 @@ impl OptionsAnalyzer {
     
     pub fn enhance_options_analysis(&self, options_data: Vec<OptionsAnalysis>) -> Result<Vec<OptionsAnalysis>, TradingBotError> {
         info!("🔧 Enhancing options analysis...");
-        
+
         let mut enhanced_options = Vec::new();
-        
+
         for option in options_data {
             let enhanced_option = self.enhance_single_option(option)?;
             enhanced_options.push(enhanced_option);
         }
-        
+
+        self.flag_put_call_parity_violations(&mut enhanced_options);
+
         Ok(enhanced_options)
     }
+
+    /// Pairs up calls/puts sharing a strike and expiry and flags any quote that
+    /// breaks put-call parity, without failing the whole batch.
+    fn flag_put_call_parity_violations(&self, options: &mut [OptionsAnalysis]) {
+        let tolerance = 0.05 * options.iter().map(|o| o.current_price).fold(0.0, f64::max).max(1.0);
+
+        for i in 0..options.len() {
+            for j in 0..options.len() {
+                if i == j {
+                    continue;
+                }
+
+                let (call, put) = (&options[i], &options[j]);
+                if call.option_type != OptionType::Call || put.option_type != OptionType::Put {
+                    continue;
+                }
+                if call.ticker != put.ticker || call.strike != put.strike || call.expiry != put.expiry {
+                    continue;
+                }
+
+                let t = call.days_to_expiry as f64 / 365.0;
+                let call_price = (call.bid + call.ask) / 2.0;
+                let put_price = (put.bid + put.ask) / 2.0;
+
+                if let Err(e) = Self::verify_put_call_parity(
+                    call_price, put_price, call.current_price, call.strike, t, self.config.risk_free_rate, tolerance,
+                ) {
+                    let reason = format!("⚠️ {}", e);
+                    options[i].reasons.push(reason.clone());
+                    options[j].reasons.push(reason);
+                }
+            }
+        }
+    }
     
     fn enhance_single_option(&self, mut option: OptionsAnalysis) -> Result<OptionsAnalysis, TradingBotError> {
+        let t = option.days_to_expiry as f64 / 365.0;
+        let market_price = (option.bid + option.ask) / 2.0;
+
+        let iv = if market_price > 0.0 {
+            Self::implied_volatility(
+                option.current_price,
+                option.strike,
+                t,
+                self.config.risk_free_rate,
+                market_price,
+                option.option_type,
+            ).unwrap_or(0.3)
+        } else {
+            0.3
+        };
+        option.implied_volatility = Some(iv);
+
         // Calculate Greeks if not already present
         if option.greeks.is_none() {
             let greeks = Self::calculate_greeks(
                 option.current_price,
                 option.strike,
-                option.days_to_expiry as f64 / 365.0,
+                t,
                 self.config.risk_free_rate,
-                0.3, // Default volatility - in practice, you'd calculate implied volatility
+                iv,
+                option.option_type,
             )?;
             option.greeks = Some(greeks);
         }
-        
+
         // Calculate risk metrics
         let _risk_metrics = self.calculate_option_risk_metrics(&option)?;
-        
+
+        // Flag model-vs-market mispricing using the contract's theoretical style
+        self.flag_model_mispricing(&mut option, t, market_price, iv);
+
         // Validate parameters
         self.validate_option_parameters(&option)?;
-        
+
         Ok(option)
     }
+
+    /// Compares the quoted mid price against the BSM/CRR theoretical price and
+    /// folds a meaningful gap into the option's score and reasons.
+    fn flag_model_mispricing(&self, option: &mut OptionsAnalysis, t: f64, market_price: f64, iv: f64) {
+        let model_price = match BsmPricer::theoretical_price(
+            option.current_price,
+            option.strike,
+            t,
+            self.config.risk_free_rate,
+            iv,
+            option.option_type,
+            option.contract_style,
+        ) {
+            Ok(price) => price,
+            Err(_) => return,
+        };
+
+        if model_price <= 0.0 {
+            return;
+        }
+
+        let relative_gap = (market_price - model_price) / model_price;
+        if relative_gap.abs() < MISPRICING_THRESHOLD {
+            return;
+        }
+
+        if relative_gap > 0.0 {
+            option.reasons.push(format!(
+                "📉 Trading {:.0}% above model price (${:.2} vs ${:.2}) - may be overpriced",
+                relative_gap * 100.0, market_price, model_price
+            ));
+            option.score -= relative_gap * 10.0;
+        } else {
+            option.reasons.push(format!(
+                "📈 Trading {:.0}% below model price (${:.2} vs ${:.2}) - may be underpriced",
+                relative_gap.abs() * 100.0, market_price, model_price
+            ));
+            option.score += relative_gap.abs() * 10.0;
+        }
+    }
+
+    /// Inverts Black-Scholes for a call or put via Newton-Raphson to recover the
+    /// volatility implied by the option's mid market price.
+    pub fn implied_volatility(
+        s: f64,
+        k: f64,
+        t: f64,
+        r: f64,
+        market_price: f64,
+        option_type: OptionType,
+    ) -> Result<f64, TradingBotError> {
+        if s <= 0.0 || k <= 0.0 || t <= 0.0 || market_price <= 0.0 {
+            return Err(TradingBotError::Calculation("Invalid inputs for implied volatility".to_string()));
+        }
+
+        let brenner_subrahmanyam = (2.0 * std::f64::consts::PI / t).sqrt() * (market_price / s);
+        let mut sigma = if brenner_subrahmanyam.is_finite() && brenner_subrahmanyam > 0.0 {
+            brenner_subrahmanyam
+        } else {
+            0.2
+        };
+
+        const MAX_ITERATIONS: usize = 50;
+        const PRICE_TOLERANCE: f64 = 1e-6;
+        const VEGA_EPSILON: f64 = 1e-8;
+
+        for _ in 0..MAX_ITERATIONS {
+            let price = Self::bs_price(s, k, t, r, sigma, option_type)?;
+            let diff = price - market_price;
+
+            if diff.abs() < PRICE_TOLERANCE {
+                return Ok(sigma);
+            }
+
+            let vega = Self::vega(s, k, t, r, sigma)?;
+            if vega.abs() < VEGA_EPSILON {
+                // Near-worthless or deep-ITM option: vega too small to converge further.
+                return Ok(sigma.max(0.01));
+            }
+
+            sigma = (sigma - diff / vega).max(0.001);
+        }
+
+        Ok(sigma)
+    }
+
+    fn bs_price(s: f64, k: f64, t: f64, r: f64, sigma: f64, option_type: OptionType) -> Result<f64, TradingBotError> {
+        let normal = Normal::new(0.0, 1.0)
+            .map_err(|e| TradingBotError::Calculation(format!("Failed to create normal distribution: {}", e)))?;
+
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+        let d2 = d1 - sigma * t.sqrt();
+
+        match option_type {
+            OptionType::Call => Ok(s * normal.cdf(d1) - k * (-r * t).exp() * normal.cdf(d2)),
+            OptionType::Put => Ok(k * (-r * t).exp() * normal.cdf(-d2) - s * normal.cdf(-d1)),
+        }
+    }
+
+    fn vega(s: f64, k: f64, t: f64, r: f64, sigma: f64) -> Result<f64, TradingBotError> {
+        let normal = Normal::new(0.0, 1.0)
+            .map_err(|e| TradingBotError::Calculation(format!("Failed to create normal distribution: {}", e)))?;
+
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+        Ok(s * t.sqrt() * normal.pdf(d1))
+    }
     
     pub fn calculate_greeks(
         s: f64, // Current stock price
@@ -56,31 +224,71 @@ impl OptionsAnalyzer {
         t: f64, // Time to expiration (in years)
         r: f64, // Risk-free rate
         sigma: f64, // Volatility
+        option_type: OptionType,
     ) -> Result<OptionGreeks, TradingBotError> {
         if s <= 0.0 || k <= 0.0 || t <= 0.0 || sigma <= 0.0 {
             return Err(TradingBotError::Calculation("Invalid option parameters".to_string()));
         }
-        
+
         let normal = Normal::new(0.0, 1.0)
             .map_err(|e| TradingBotError::Calculation(format!("Failed to create normal distribution: {}", e)))?;
-        
-        let d1 = (s.ln() / k + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
         let d2 = d1 - sigma * t.sqrt();
-        
-        // Calculate Greeks for call option
-        let delta = normal.cdf(d1);
+
+        // Gamma and vega are identical for calls and puts.
         let gamma = normal.pdf(d1) / (s * sigma * t.sqrt());
-        let theta = -s * normal.pdf(d1) * sigma / (2.0 * t.sqrt()) - 
-                   r * k * (-r * t).exp() * normal.cdf(d2);
         let vega = s * t.sqrt() * normal.pdf(d1);
-        
+
+        let (delta, theta, rho) = match option_type {
+            OptionType::Call => {
+                let delta = normal.cdf(d1);
+                let theta = -s * normal.pdf(d1) * sigma / (2.0 * t.sqrt())
+                    - r * k * (-r * t).exp() * normal.cdf(d2);
+                let rho = k * t * (-r * t).exp() * normal.cdf(d2);
+                (delta, theta, rho)
+            }
+            OptionType::Put => {
+                let delta = normal.cdf(d1) - 1.0;
+                let theta = -s * normal.pdf(d1) * sigma / (2.0 * t.sqrt())
+                    + r * k * (-r * t).exp() * normal.cdf(-d2);
+                let rho = -k * t * (-r * t).exp() * normal.cdf(-d2);
+                (delta, theta, rho)
+            }
+        };
+
         Ok(OptionGreeks {
             delta,
             gamma,
             theta,
             vega,
+            rho,
         })
     }
+
+    /// Checks that the quoted call/put pair at the same strike and expiry obeys
+    /// put-call parity (`C - P ≈ S - K·e^(-rT)`), flagging an arbitrage-looking quote.
+    pub fn verify_put_call_parity(
+        call_price: f64,
+        put_price: f64,
+        s: f64,
+        k: f64,
+        t: f64,
+        r: f64,
+        tolerance: f64,
+    ) -> Result<(), TradingBotError> {
+        let lhs = call_price - put_price;
+        let rhs = s - k * (-r * t).exp();
+
+        if (lhs - rhs).abs() > tolerance {
+            return Err(TradingBotError::Calculation(format!(
+                "Put-call parity violated: C - P = {:.4} but S - K*e^(-rT) = {:.4} (diff {:.4})",
+                lhs, rhs, (lhs - rhs).abs()
+            )));
+        }
+
+        Ok(())
+    }
     
     fn calculate_option_risk_metrics(&self, option: &OptionsAnalysis) -> Result<f64, TradingBotError> {
         // Calculate a simple risk score based on Greeks and other factors
@@ -0,0 +1,205 @@
+use crate::error::TradingBotError;
+use chrono::{DateTime, Utc};
+
+/// A currently-held lot: shares bought at `entry_price` on `entry_date`,
+/// currently marked at `current_price`.
+#[derive(Debug, Clone)]
+pub struct Position {
+    pub ticker: String,
+    pub entry_date: DateTime<Utc>,
+    pub entry_price: f64,
+    pub shares: f64,
+    pub current_price: f64,
+}
+
+/// Realized vs. unrealized P&L for one held position.
+#[derive(Debug, Clone)]
+pub struct PositionPnl {
+    pub ticker: String,
+    pub cost_basis: f64,
+    pub market_value: f64,
+    /// Always 0.0: `Position` only models currently-held lots, so nothing has
+    /// been closed out yet. A real fill-based ledger would populate this from
+    /// matched sells.
+    pub realized_pnl: f64,
+    pub unrealized_pnl: f64,
+}
+
+/// Realized performance of a running portfolio, as opposed to
+/// `PortfolioSummary.expected_return`'s forward-looking Kelly estimate.
+#[derive(Debug, Clone)]
+pub struct PerformanceAnalysis {
+    /// Annualized internal rate of return across all position entries and
+    /// cash flows, solved via bisection.
+    pub irr: f64,
+    /// Chained sub-period return between cash-flow dates, so deposit/withdrawal
+    /// timing doesn't distort the result the way `irr` can.
+    pub time_weighted_return: f64,
+    pub position_pnl: Vec<PositionPnl>,
+    pub total_market_value: f64,
+}
+
+const IRR_LOWER_BOUND: f64 = -0.99;
+const IRR_UPPER_BOUND: f64 = 10.0;
+const IRR_MAX_ITERATIONS: u32 = 100;
+const IRR_TOLERANCE: f64 = 1e-8;
+
+/// Computes realized return metrics for a running portfolio of open positions
+/// plus a ledger of external deposit/withdrawal cash flows.
+pub struct PerformanceAnalyzer;
+
+impl PerformanceAnalyzer {
+    pub fn new() -> Self {
+        Self
+    }
+
+    pub fn portfolio_performance(
+        &self,
+        positions: Vec<Position>,
+        cash_flows: Vec<(DateTime<Utc>, f64)>,
+        as_of: DateTime<Utc>,
+    ) -> Result<PerformanceAnalysis, TradingBotError> {
+        if positions.is_empty() {
+            return Err(TradingBotError::Calculation("No positions to analyze".to_string()));
+        }
+
+        let position_pnl: Vec<PositionPnl> = positions.iter().map(|p| {
+            let cost_basis = p.shares * p.entry_price;
+            let market_value = p.shares * p.current_price;
+            PositionPnl {
+                ticker: p.ticker.clone(),
+                cost_basis,
+                market_value,
+                realized_pnl: 0.0,
+                unrealized_pnl: market_value - cost_basis,
+            }
+        }).collect();
+
+        let total_market_value: f64 = position_pnl.iter().map(|p| p.market_value).sum();
+
+        // Money-weighted return: every position entry is a buy (outflow), every
+        // cash flow is a deposit (outflow, negative) or withdrawal (inflow,
+        // positive) as supplied by the caller, and the current mark-to-market
+        // value is a terminal inflow.
+        let mut events: Vec<(DateTime<Utc>, f64)> = positions.iter()
+            .map(|p| (p.entry_date, -p.shares * p.entry_price))
+            .collect();
+        events.extend(cash_flows.iter().copied());
+        events.push((as_of, total_market_value));
+
+        let irr = Self::solve_irr(&events, as_of);
+        let time_weighted_return = Self::time_weighted_return(&positions, &cash_flows, total_market_value, as_of);
+
+        Ok(PerformanceAnalysis {
+            irr,
+            time_weighted_return,
+            position_pnl,
+            total_market_value,
+        })
+    }
+
+    /// Net present value of `events` at rate `r`, discounting each cash flow
+    /// by the number of days between it and `as_of`.
+    fn npv(events: &[(DateTime<Utc>, f64)], as_of: DateTime<Utc>, r: f64) -> f64 {
+        events.iter().map(|(date, amount)| {
+            let days = (as_of - *date).num_days().max(0) as f64;
+            amount / (1.0 + r).powf(days / 365.0)
+        }).sum()
+    }
+
+    /// Solves `Σ cashflow_i / (1+r)^(days_i/365) = 0` for `r` via bisection
+    /// over `(IRR_LOWER_BOUND, IRR_UPPER_BOUND)`. Falls back to 0.0 if the
+    /// cash flows never cross zero NPV in that range (e.g. all outflows).
+    fn solve_irr(events: &[(DateTime<Utc>, f64)], as_of: DateTime<Utc>) -> f64 {
+        let mut low = IRR_LOWER_BOUND;
+        let mut high = IRR_UPPER_BOUND;
+        let mut npv_low = Self::npv(events, as_of, low);
+        let npv_high = Self::npv(events, as_of, high);
+
+        if npv_low.signum() == npv_high.signum() {
+            return 0.0;
+        }
+
+        for _ in 0..IRR_MAX_ITERATIONS {
+            let mid = (low + high) / 2.0;
+            let npv_mid = Self::npv(events, as_of, mid);
+
+            if npv_mid.abs() < IRR_TOLERANCE {
+                return mid;
+            }
+
+            if npv_mid.signum() == npv_low.signum() {
+                low = mid;
+                npv_low = npv_mid;
+            } else {
+                high = mid;
+            }
+        }
+
+        (low + high) / 2.0
+    }
+
+    /// Approximates each position's price path as a straight line between its
+    /// entry and current mark (no interim price series is available), then
+    /// chains sub-period returns between every position entry date and
+    /// external cash-flow date so deposit/withdrawal timing doesn't distort
+    /// the result.
+    fn time_weighted_return(
+        positions: &[Position],
+        cash_flows: &[(DateTime<Utc>, f64)],
+        total_market_value: f64,
+        as_of: DateTime<Utc>,
+    ) -> f64 {
+        // `include_entering` decides whether a position entered exactly at
+        // `at` counts, giving the value just before or just after that entry.
+        let value_at = |at: DateTime<Utc>, include_entering: bool| -> f64 {
+            positions.iter().map(|p| {
+                if at < p.entry_date || (!include_entering && at == p.entry_date) {
+                    return 0.0;
+                }
+                let total_span = (as_of - p.entry_date).num_seconds().max(1) as f64;
+                let elapsed = (at - p.entry_date).num_seconds().max(0) as f64;
+                let fraction = (elapsed / total_span).min(1.0);
+                let interpolated_price = p.entry_price + (p.current_price - p.entry_price) * fraction;
+                p.shares * interpolated_price
+            }).sum()
+        };
+
+        let mut checkpoints: Vec<DateTime<Utc>> = cash_flows.iter()
+            .map(|(date, _)| *date)
+            .chain(positions.iter().map(|p| p.entry_date))
+            .filter(|date| *date <= as_of)
+            .collect();
+        checkpoints.sort();
+        checkpoints.dedup();
+
+        let mut twr = 1.0;
+        let mut value_before = 0.0;
+
+        for &checkpoint in &checkpoints {
+            let value_end = value_at(checkpoint, false);
+            if value_before > 0.0 {
+                twr *= 1.0 + (value_end - value_before) / value_before;
+            }
+
+            // The next sub-period opens just after any entry at this
+            // checkpoint. External cash flows aren't modeled in `value_at`
+            // (it only interpolates position prices), so they must not be
+            // netted back out here, or they'd be subtracted an extra time on
+            // top of the price-only valuation.
+            value_before = value_at(checkpoint, true);
+        }
+
+        if value_before > 0.0 {
+            twr *= 1.0 + (total_market_value - value_before) / value_before;
+        }
+
+        twr - 1.0
+    }
+}
+
+impl Default for PerformanceAnalyzer {
+    fn default() -> Self {
+        Self::new()
+    }
+}
@@ -1,9 +1,31 @@
 use crate::{
-    models::PortfolioSummary,
+    models::{PortfolioAllocation, PortfolioSummary},
     error::TradingBotError,
     config::TradingConfig,
+    protections::ProtectionLock,
+    rebalance::{self, Holding, RebalanceResult},
 };
-use log::{info};
+use chrono::Utc;
+use log::{info, warn};
+use std::collections::HashMap;
+
+/// A currently-held position, as tracked outside of this one-shot analysis.
+#[derive(Debug, Clone)]
+pub struct HeldPosition {
+    pub ticker: String,
+    pub dollar_value: f64,
+    pub kelly_fraction: f64,
+}
+
+/// An incremental buy/sell delta produced by `compute_position_adjustments`,
+/// rather than a clean-slate reallocation.
+#[derive(Debug, Clone)]
+pub struct AllocationAdjustment {
+    pub ticker: String,
+    pub delta_amount: f64,
+    pub new_weight: f64,
+    pub reason: String,
+}
 
 pub struct PortfolioManager {
     config: TradingConfig,
@@ -13,24 +35,259 @@ impl PortfolioManager {
     pub fn new(config: TradingConfig) -> Self {
         Self { config }
     }
-    
-    pub fn enhance_portfolio_summary(&self, portfolio_summary: PortfolioSummary) -> Result<PortfolioSummary, TradingBotError> {
+
+    pub fn enhance_portfolio_summary(
+        &self,
+        portfolio_summary: PortfolioSummary,
+        locks: &[ProtectionLock],
+    ) -> Result<PortfolioSummary, TradingBotError> {
         info!("🔧 Enhancing portfolio summary...");
-        
+
+        let now = Utc::now();
+        let globally_locked = locks.iter().any(|l| l.asset.is_none() && l.locked_until > now);
+
+        let mut total_allocated = portfolio_summary.total_allocated;
+        let mut cash_remaining = portfolio_summary.cash_remaining;
+
+        let allocations = portfolio_summary.allocations.into_iter().map(|mut alloc| {
+            let asset_locked = locks.iter().any(|l| {
+                l.locked_until > now && l.asset.as_deref() == Some(alloc.ticker.as_str())
+            });
+
+            if globally_locked || asset_locked {
+                warn!("🔒 Zeroing allocation to {} due to an active protection lock", alloc.ticker);
+                total_allocated -= alloc.dollar_allocation;
+                cash_remaining += alloc.dollar_allocation;
+                alloc.dollar_allocation = 0.0;
+                alloc.shares_to_buy = 0;
+            }
+
+            alloc
+        }).collect::<Vec<_>>();
+
+        let number_of_positions = allocations.iter().filter(|a| a.dollar_allocation > 0.0).count();
+
         // Add additional risk metrics
         let enhanced_summary = PortfolioSummary {
-            allocations: portfolio_summary.allocations,
-            total_allocated: portfolio_summary.total_allocated,
-            cash_remaining: portfolio_summary.cash_remaining,
+            allocations,
+            total_allocated,
+            cash_remaining,
             allocation_percentage: portfolio_summary.allocation_percentage,
             expected_return: portfolio_summary.expected_return,
             portfolio_volatility: portfolio_summary.portfolio_volatility,
             portfolio_sharpe: portfolio_summary.portfolio_sharpe,
             max_drawdown_estimate: portfolio_summary.max_drawdown_estimate,
-            number_of_positions: portfolio_summary.number_of_positions,
+            number_of_positions,
             concentration_risk: portfolio_summary.concentration_risk,
+            diversification_ratio: portfolio_summary.diversification_ratio,
         };
-        
+
         Ok(enhanced_summary)
     }
+
+    /// Computes incremental buy/sell deltas from `held` toward `targets`, scaling
+    /// into rising-conviction names in tranches and scaling out when conviction
+    /// falls or `concentration_risk` breaches the configured threshold.
+    pub fn compute_position_adjustments(
+        &self,
+        held: &[HeldPosition],
+        targets: &[PortfolioAllocation],
+        cash_remaining: f64,
+        concentration_risk: f64,
+    ) -> Result<Vec<AllocationAdjustment>, TradingBotError> {
+        info!("📐 Computing position adjustments for {} targets...", targets.len());
+
+        let held_value: f64 = held.iter().map(|h| h.dollar_value).sum();
+        let net_value = held_value + cash_remaining;
+
+        if net_value <= 0.0 {
+            return Err(TradingBotError::Calculation("Net portfolio value must be positive".to_string()));
+        }
+
+        let tranches = self.config.scale_in_tranches.max(1) as f64;
+        let over_concentrated = concentration_risk > self.config.concentration_risk_threshold;
+        let mut available_cash = cash_remaining;
+        let mut adjustments = Vec::with_capacity(targets.len());
+
+        for target in targets {
+            let existing = held.iter().find(|h| h.ticker == target.ticker);
+            let current_value = existing.map(|h| h.dollar_value).unwrap_or(0.0);
+            let held_kelly = existing.map(|h| h.kelly_fraction).unwrap_or(0.0);
+
+            let capped_target_value = target.dollar_allocation
+                .min(self.config.max_allocation_per_position * net_value);
+            let gap = capped_target_value - current_value;
+
+            let (delta_amount, reason) = if over_concentrated && current_value > 0.0 {
+                (-current_value, "scaling out fully: concentration_risk exceeds threshold".to_string())
+            } else if gap > 0.0 && target.kelly_fraction >= held_kelly {
+                let tranche_amount = (gap / tranches).min(available_cash).max(0.0);
+                if current_value + tranche_amount < self.config.min_allocation {
+                    (0.0, "skipped: resulting position below min_allocation".to_string())
+                } else {
+                    (tranche_amount, format!("scaling in 1/{:.0} tranche on rising conviction", tranches))
+                }
+            } else if gap < 0.0 {
+                let tranche_amount = (gap / tranches).max(-current_value);
+                (tranche_amount, format!("scaling out 1/{:.0} tranche: target weight falling", tranches))
+            } else {
+                (0.0, "no adjustment needed".to_string())
+            };
+
+            available_cash -= delta_amount;
+
+            let new_value = (current_value + delta_amount).max(0.0);
+
+            adjustments.push(AllocationAdjustment {
+                ticker: target.ticker.clone(),
+                delta_amount,
+                new_weight: new_value / net_value,
+                reason,
+            });
+        }
+
+        Ok(adjustments)
+    }
+
+    /// Emits the concrete buy/sell trades needed to move `holdings` to
+    /// `target_weights`, respecting `[min_weight, max_weight]` per-asset bounds
+    /// and a `min_cash_reserve` kept uninvested.
+    pub fn rebalance_portfolio(
+        &self,
+        holdings: &[Holding],
+        cash: f64,
+        target_weights: &[(String, f64)],
+        min_weight: f64,
+        max_weight: f64,
+        min_cash_reserve: f64,
+    ) -> Result<RebalanceResult, TradingBotError> {
+        rebalance::rebalance_portfolio(
+            holdings,
+            cash,
+            target_weights,
+            min_weight,
+            max_weight,
+            min_cash_reserve,
+            self.config.min_allocation,
+        )
+    }
+
+    /// Rebalances an existing `PortfolioSummary` toward `targets` (ticker,
+    /// target weight) in two passes, like a real rebalancer. Named distinctly
+    /// from the share-based `rebalance_portfolio` above since Rust has no
+    /// method overloading.
+    ///
+    /// Pass 1 (bottom-up) computes a `[0, max]` dollar bound per asset, where
+    /// `max` is capped by `concentration_risk_threshold` of the total
+    /// portfolio value. Pass 2 (top-down) distributes
+    /// `total_allocated + cash_remaining` across positions to match the
+    /// target weights, clamping to those bounds and redistributing any
+    /// resulting overflow/underflow proportionally across the assets that
+    /// didn't clamp, until it converges or no unconstrained assets remain.
+    /// Trades smaller than `min_trade_volume` are left in place to avoid
+    /// dust-sized noise orders. Targets for tickers absent from the current
+    /// allocations are skipped, since there's no current price to size a
+    /// brand-new position from.
+    pub fn rebalance_to_targets(
+        &self,
+        mut summary: PortfolioSummary,
+        targets: Vec<(String, f64)>,
+        min_trade_volume: f64,
+    ) -> Result<PortfolioSummary, TradingBotError> {
+        info!("⚖️ Rebalancing portfolio to {} targets...", targets.len());
+
+        let total_value = summary.total_allocated + summary.cash_remaining;
+        if total_value <= 0.0 {
+            return Err(TradingBotError::Calculation("Portfolio has no value to rebalance".to_string()));
+        }
+
+        let max_per_position = self.config.concentration_risk_threshold * total_value;
+
+        // Only rebalance tickers we can actually price.
+        let mut weights: HashMap<String, f64> = HashMap::new();
+        for (ticker, weight) in &targets {
+            if summary.allocations.iter().any(|a| &a.ticker == ticker) {
+                weights.insert(ticker.clone(), *weight);
+            } else {
+                warn!("Skipping rebalance target {}: no existing allocation to size it from", ticker);
+            }
+        }
+
+        // Pass 1 (bottom-up): bounds are [0, max_per_position] for every
+        // targeted asset; untargeted assets are pinned at their current value.
+        let mut desired: HashMap<String, f64> = HashMap::new();
+        for allocation in &summary.allocations {
+            let raw = weights.get(&allocation.ticker).map(|w| w * total_value)
+                .unwrap_or(allocation.dollar_allocation);
+            desired.insert(allocation.ticker.clone(), raw.clamp(0.0, max_per_position));
+        }
+
+        // Pass 2 (top-down): redistribute any shortfall/excess against
+        // `total_value` proportionally across unclamped, targeted assets
+        // until the allocations sum to `total_value` or nothing is left to
+        // adjust.
+        for _ in 0..8 {
+            let allocated: f64 = desired.values().sum();
+            let remainder = total_value - allocated;
+            if remainder.abs() < 0.01 {
+                break;
+            }
+
+            let adjustable: Vec<String> = weights.keys()
+                .filter(|t| {
+                    let value = desired[t.as_str()];
+                    if remainder > 0.0 { value < max_per_position } else { value > 0.0 }
+                })
+                .cloned()
+                .collect();
+
+            if adjustable.is_empty() {
+                break;
+            }
+
+            let adjustable_weight: f64 = adjustable.iter().map(|t| weights[t]).sum();
+            for ticker in &adjustable {
+                let share = if adjustable_weight > 0.0 {
+                    remainder * (weights[ticker] / adjustable_weight)
+                } else {
+                    remainder / adjustable.len() as f64
+                };
+                let value = desired.get_mut(ticker).unwrap();
+                *value = (*value + share).clamp(0.0, max_per_position);
+            }
+        }
+
+        // Apply the converged targets, skipping dust-sized trades.
+        let mut cash_delta = 0.0;
+        for allocation in summary.allocations.iter_mut() {
+            let Some(&target_value) = desired.get(&allocation.ticker) else { continue };
+            let trade = target_value - allocation.dollar_allocation;
+            if trade.abs() < min_trade_volume {
+                allocation.delta_shares = 0;
+                continue;
+            }
+
+            allocation.delta_shares = if allocation.current_price > 0.0 {
+                (trade / allocation.current_price).round() as i64
+            } else {
+                0
+            };
+            allocation.dollar_allocation = target_value;
+            if allocation.current_price > 0.0 {
+                allocation.shares_to_buy = (target_value / allocation.current_price).floor().max(0.0) as u32;
+            }
+            cash_delta -= trade;
+        }
+
+        summary.cash_remaining += cash_delta;
+        summary.total_allocated = summary.allocations.iter().map(|a| a.dollar_allocation).sum();
+        summary.number_of_positions = summary.allocations.iter().filter(|a| a.dollar_allocation > 0.0).count();
+        summary.allocation_percentage = if total_value > 0.0 {
+            summary.total_allocated / total_value * 100.0
+        } else {
+            0.0
+        };
+
+        Ok(summary)
+    }
 }
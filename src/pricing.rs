@@ -0,0 +1,82 @@
+use crate::{error::TradingBotError, models::OptionType};
+use serde::{Deserialize, Serialize};
+use statrs::distribution::{ContinuousCDF, Normal};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum ContractStyle {
+    /// Exercisable only at expiry; priced with closed-form Black-Scholes-Merton.
+    European,
+    /// Exercisable any time up to expiry; priced with a CRR binomial lattice.
+    American,
+}
+
+const CRR_LATTICE_STEPS: usize = 100;
+
+/// Analytical Black-Scholes-Merton pricer, with a binomial (Cox-Ross-Rubinstein)
+/// lattice fallback for American-style early-exercise pricing.
+pub struct BsmPricer;
+
+impl BsmPricer {
+    /// Theoretical fair value for the given contract style.
+    pub fn theoretical_price(
+        s: f64,
+        k: f64,
+        t: f64,
+        r: f64,
+        sigma: f64,
+        option_type: OptionType,
+        style: ContractStyle,
+    ) -> Result<f64, TradingBotError> {
+        if s <= 0.0 || k <= 0.0 || t <= 0.0 || sigma <= 0.0 {
+            return Err(TradingBotError::Calculation("Invalid pricing inputs".to_string()));
+        }
+
+        match style {
+            ContractStyle::European => Self::european_price(s, k, t, r, sigma, option_type),
+            ContractStyle::American => Ok(Self::american_price_crr(s, k, t, r, sigma, option_type, CRR_LATTICE_STEPS)),
+        }
+    }
+
+    pub fn european_price(s: f64, k: f64, t: f64, r: f64, sigma: f64, option_type: OptionType) -> Result<f64, TradingBotError> {
+        let normal = Normal::new(0.0, 1.0)
+            .map_err(|e| TradingBotError::Calculation(format!("Failed to create normal distribution: {}", e)))?;
+
+        let d1 = ((s / k).ln() + (r + sigma * sigma / 2.0) * t) / (sigma * t.sqrt());
+        let d2 = d1 - sigma * t.sqrt();
+
+        match option_type {
+            OptionType::Call => Ok(s * normal.cdf(d1) - k * (-r * t).exp() * normal.cdf(d2)),
+            OptionType::Put => Ok(k * (-r * t).exp() * normal.cdf(-d2) - s * normal.cdf(-d1)),
+        }
+    }
+
+    /// Cox-Ross-Rubinstein binomial lattice, checking for early exercise at
+    /// every node so American puts/calls on dividend-paying-like underlyings
+    /// price above their European counterpart when early exercise is optimal.
+    fn american_price_crr(s: f64, k: f64, t: f64, r: f64, sigma: f64, option_type: OptionType, steps: usize) -> f64 {
+        let dt = t / steps as f64;
+        let u = (sigma * dt.sqrt()).exp();
+        let d = 1.0 / u;
+        let p = ((r * dt).exp() - d) / (u - d);
+        let discount = (-r * dt).exp();
+
+        let payoff = |spot: f64| match option_type {
+            OptionType::Call => (spot - k).max(0.0),
+            OptionType::Put => (k - spot).max(0.0),
+        };
+
+        let mut values: Vec<f64> = (0..=steps)
+            .map(|i| payoff(s * u.powi(i as i32) * d.powi((steps - i) as i32)))
+            .collect();
+
+        for step in (0..steps).rev() {
+            for i in 0..=step {
+                let spot = s * u.powi(i as i32) * d.powi((step - i) as i32);
+                let continuation = discount * (p * values[i + 1] + (1.0 - p) * values[i]);
+                values[i] = continuation.max(payoff(spot));
+            }
+        }
+
+        values[0]
+    }
+}
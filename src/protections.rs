@@ -0,0 +1,120 @@
+use crate::{
+    config::ProtectionConfig,
+    utils::calculate_max_drawdown,
+};
+use chrono::{DateTime, Duration, Utc};
+use log::warn;
+
+/// A single closed trade, used by `StoplossGuard` and `CooldownPeriod` to look
+/// back over recent activity.
+#[derive(Debug, Clone)]
+pub struct TradeRecord {
+    pub asset: String,
+    pub closed_at: DateTime<Utc>,
+    pub is_loss: bool,
+}
+
+/// A lock produced by a guard. `asset == None` means the lock applies globally
+/// (e.g. a portfolio-wide drawdown breach).
+#[derive(Debug, Clone)]
+pub struct ProtectionLock {
+    pub asset: Option<String>,
+    pub locked_until: DateTime<Utc>,
+    pub reason: String,
+}
+
+/// Halts new entries when too many recent trades closed at a loss.
+pub struct StoplossGuard {
+    config: ProtectionConfig,
+}
+
+impl StoplossGuard {
+    pub fn new(config: ProtectionConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn evaluate(&self, trade_history: &[TradeRecord], now: DateTime<Utc>) -> Option<ProtectionLock> {
+        let window_start = now - Duration::minutes(self.config.stoploss_lookback_minutes);
+
+        let losing_exits = trade_history.iter()
+            .filter(|t| t.closed_at >= window_start && t.closed_at <= now && t.is_loss)
+            .count();
+
+        if losing_exits > self.config.stoploss_trade_limit {
+            warn!("🛑 StoplossGuard triggered: {} losing exits in lookback window", losing_exits);
+            return Some(ProtectionLock {
+                asset: None,
+                locked_until: now + Duration::minutes(self.config.stoploss_lock_minutes),
+                reason: format!(
+                    "{} losing exits in the last {} minutes exceeds threshold of {}",
+                    losing_exits, self.config.stoploss_lookback_minutes, self.config.stoploss_trade_limit
+                ),
+            });
+        }
+
+        None
+    }
+}
+
+/// Halts new entries when the trailing equity curve has breached a drawdown limit.
+pub struct MaxDrawdownProtection {
+    config: ProtectionConfig,
+}
+
+impl MaxDrawdownProtection {
+    pub fn new(config: ProtectionConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn evaluate(&self, equity_curve: &[f64], now: DateTime<Utc>) -> Option<ProtectionLock> {
+        if equity_curve.len() < 2 {
+            return None;
+        }
+
+        let window_start = equity_curve.len().saturating_sub(self.config.max_drawdown_lookback_periods);
+        let trailing = &equity_curve[window_start..];
+        let drawdown = calculate_max_drawdown(trailing);
+
+        if drawdown > self.config.max_drawdown_limit {
+            warn!("🛑 MaxDrawdownProtection triggered: {:.2}% drawdown", drawdown * 100.0);
+            return Some(ProtectionLock {
+                asset: None,
+                locked_until: now + Duration::minutes(self.config.max_drawdown_lock_minutes),
+                reason: format!(
+                    "Trailing drawdown of {:.2}% exceeds limit of {:.2}%",
+                    drawdown * 100.0, self.config.max_drawdown_limit * 100.0
+                ),
+            });
+        }
+
+        None
+    }
+}
+
+/// Forbids re-entering an asset for a fixed number of minutes after it was last closed.
+pub struct CooldownPeriod {
+    config: ProtectionConfig,
+}
+
+impl CooldownPeriod {
+    pub fn new(config: ProtectionConfig) -> Self {
+        Self { config }
+    }
+
+    pub fn evaluate(&self, trade_history: &[TradeRecord], now: DateTime<Utc>) -> Vec<ProtectionLock> {
+        let mut locks = Vec::new();
+
+        for trade in trade_history {
+            let locked_until = trade.closed_at + Duration::minutes(self.config.cooldown_minutes);
+            if locked_until > now {
+                locks.push(ProtectionLock {
+                    asset: Some(trade.asset.clone()),
+                    locked_until,
+                    reason: format!("{} closed recently; cooldown active until {}", trade.asset, locked_until),
+                });
+            }
+        }
+
+        locks
+    }
+}
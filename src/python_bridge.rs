@@ -1,8 +1,18 @@
 use pyo3::prelude::*;
 use pyo3::types::PyDict;
+use crate::backtest::PriceBar;
 use crate::error::TradingBotError;
 use crate::models::*;
+use crate::pricing::ContractStyle;
+use crate::utils::{calculate_max_drawdown, calculate_sharpe_ratio};
+use chrono::{DateTime, Utc};
 use log::info;
+use std::collections::HashMap;
+
+/// Span (in bars) of the EWMA trend filter used by `backtest_strategy`.
+const TREND_EWMA_SPAN: usize = 20;
+/// Notional starting equity for the single-ticker strategy backtest.
+const STRATEGY_STARTING_EQUITY: f64 = 10_000.0;
 
 pub struct PythonBridge {
     py_runtime: Option<PyObject>,
@@ -109,9 +119,12 @@ impl PythonBridge {
                    let beta: Option<f64> = result.get_item("Beta")?.extract().ok();
                    let volatility: f64 = result.get_item("Volatility")?.extract()?;
             
-            // For now, we'll use a placeholder for returns
-            // In a full implementation, you'd extract historical returns
-            let returns = vec![0.0]; // Placeholder
+            // Historical daily returns, used downstream for covariance-based
+            // portfolio risk; empty if Python didn't supply any.
+            let returns: Vec<f64> = result.get_item("Daily_Returns")
+                .ok()
+                .and_then(|v| v.extract::<Vec<f64>>().ok())
+                .unwrap_or_default();
             
             Ok(StockData {
                 ticker,
@@ -196,6 +209,9 @@ impl PythonBridge {
                         score,
                         reasons,
                         greeks,
+                        implied_volatility: None,
+                        option_type: OptionType::Call,
+                        contract_style: ContractStyle::American,
                     });
                 }
             }
@@ -311,6 +327,7 @@ impl PythonBridge {
                         sharpe_ratio,
                         doubling_score,
                         reasons,
+                        delta_shares: 0,
                     });
                 }
             }
@@ -319,23 +336,47 @@ impl PythonBridge {
             let expected_return = allocations.iter()
                 .map(|a| a.win_probability * a.avg_gain * (a.dollar_allocation / total_allocated))
                 .sum::<f64>();
-            
-            let portfolio_volatility = allocations.iter()
+
+            // Naive weighted-sum volatility, kept around only to derive the
+            // diversification ratio against the true covariance-based figure.
+            let weighted_avg_volatility = allocations.iter()
                 .map(|a| a.volatility * (a.dollar_allocation / total_allocated))
                 .sum::<f64>();
-            
-            let portfolio_sharpe = allocations.iter()
-                .map(|a| a.sharpe_ratio * (a.dollar_allocation / total_allocated))
+
+            let returns_by_ticker: HashMap<String, Vec<f64>> = stocks_data.iter()
+                .map(|s| (s.ticker.clone(), s.returns.clone()))
+                .collect();
+            let tickers: Vec<String> = allocations.iter().map(|a| a.ticker.clone()).collect();
+            let weights: Vec<f64> = allocations.iter()
+                .map(|a| if total_allocated > 0.0 { a.dollar_allocation / total_allocated } else { 0.0 })
+                .collect();
+            let covariance = covariance_matrix(&tickers, &returns_by_ticker);
+
+            let portfolio_variance = (0..weights.len())
+                .map(|i| (0..weights.len()).map(|j| weights[i] * weights[j] * covariance[i][j]).sum::<f64>())
                 .sum::<f64>();
-            
+            let portfolio_volatility = portfolio_variance.max(0.0).sqrt();
+
+            let diversification_ratio = if portfolio_volatility > 0.0 {
+                weighted_avg_volatility / portfolio_volatility
+            } else {
+                1.0
+            };
+
+            let portfolio_sharpe = if portfolio_volatility > 0.0 {
+                expected_return / portfolio_volatility
+            } else {
+                0.0
+            };
+
             let max_drawdown_estimate = portfolio_volatility * 2.0;
-            
+
             let concentration_risk = allocations.iter()
                 .map(|a| a.dollar_allocation / total_allocated)
                 .fold(0.0, f64::max);
-            
+
             let number_of_positions = allocations.len();
-            
+
             Ok(PortfolioSummary {
                 allocations,
                 total_allocated,
@@ -347,10 +388,158 @@ impl PythonBridge {
                 max_drawdown_estimate,
                 number_of_positions,
                 concentration_risk,
+                diversification_ratio,
             })
         })
     }
-    
+
+    /// Pulls `ticker`'s historical OHLCV bars between `start` and `end` from
+    /// Python, at the given `timeframe` (e.g. "1d").
+    pub fn fetch_price_history(
+        &self,
+        ticker: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+    ) -> Result<Vec<PriceBar>, TradingBotError> {
+        Python::with_gil(|py| -> Result<Vec<PriceBar>, TradingBotError> {
+            let modules = self.py_runtime.as_ref().unwrap().bind(py);
+            let main_item = modules.get_item("main").unwrap();
+            let main_module = main_item.downcast::<PyModule>()
+                .map_err(|e| TradingBotError::PythonBridge(format!("Failed to downcast main module: {}", e)))?;
+
+            let result = main_module.call_method1(
+                "get_ohlcv_history",
+                (ticker, start.to_rfc3339(), end.to_rfc3339(), timeframe),
+            )?;
+
+            let mut bars = Vec::new();
+            if let Ok(py_list) = result.downcast::<pyo3::types::PyList>() {
+                for item in py_list.iter() {
+                    let timestamp_str: String = item.get_item("timestamp")?.extract()?;
+                    let timestamp = DateTime::parse_from_rfc3339(&timestamp_str)
+                        .map(|dt| dt.with_timezone(&Utc))
+                        .map_err(|e| TradingBotError::DataProcessing(format!("Invalid bar timestamp: {}", e)))?;
+
+                    bars.push(PriceBar {
+                        timestamp,
+                        open: item.get_item("open")?.extract()?,
+                        high: item.get_item("high")?.extract()?,
+                        low: item.get_item("low")?.extract()?,
+                        close: item.get_item("close")?.extract()?,
+                        volume: item.get_item("volume")?.extract()?,
+                    });
+                }
+            }
+
+            Ok(bars)
+        })
+    }
+
+    /// Replays an EWMA trend-following rule bar-by-bar against `ticker`'s
+    /// historical OHLCV (pulled from Python), benchmarking it against a
+    /// buy-and-hold position opened at the first bar. Buys full cash into the
+    /// position when `close > EWMA(TREND_EWMA_SPAN)`, closes it when the close
+    /// falls back below.
+    pub fn backtest_strategy(
+        &self,
+        ticker: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+    ) -> Result<StrategyBacktestResult, TradingBotError> {
+        info!("🧪 Backtesting EWMA trend strategy for {}", ticker);
+
+        let bars = self.fetch_price_history(ticker, start, end, timeframe)?;
+
+        if bars.len() < TREND_EWMA_SPAN + 1 {
+            return Err(TradingBotError::DataProcessing(format!(
+                "Not enough OHLCV history for {} to backtest (need at least {} bars)",
+                ticker, TREND_EWMA_SPAN + 1,
+            )));
+        }
+
+        let alpha = 2.0 / (TREND_EWMA_SPAN as f64 + 1.0);
+        let mut ewma = bars[0].close;
+
+        let starting_price = bars[0].close;
+        let benchmark_shares = if starting_price > 0.0 { STRATEGY_STARTING_EQUITY / starting_price } else { 0.0 };
+
+        let mut cash = STRATEGY_STARTING_EQUITY;
+        let mut shares = 0.0;
+        let mut in_position = false;
+        let mut entry_date = bars[0].timestamp;
+        let mut entry_price = 0.0;
+
+        let mut equity_curve = Vec::with_capacity(bars.len());
+        let mut trades = Vec::new();
+
+        for bar in &bars {
+            ewma = alpha * bar.close + (1.0 - alpha) * ewma;
+
+            if !in_position && bar.close > ewma {
+                shares = cash / bar.close;
+                cash = 0.0;
+                in_position = true;
+                entry_date = bar.timestamp;
+                entry_price = bar.close;
+            } else if in_position && bar.close <= ewma {
+                cash = shares * bar.close;
+                trades.push(StrategyTrade {
+                    entry_date,
+                    exit_date: bar.timestamp,
+                    entry_price,
+                    exit_price: bar.close,
+                    return_pct: if entry_price > 0.0 { (bar.close - entry_price) / entry_price } else { 0.0 },
+                });
+                shares = 0.0;
+                in_position = false;
+            }
+
+            equity_curve.push(cash + shares * bar.close);
+        }
+
+        let final_equity = *equity_curve.last().unwrap();
+        let total_return = (final_equity - STRATEGY_STARTING_EQUITY) / STRATEGY_STARTING_EQUITY;
+
+        let final_close = bars.last().unwrap().close;
+        let benchmark_equity = benchmark_shares * final_close;
+        let benchmark_return = (benchmark_equity - STRATEGY_STARTING_EQUITY) / STRATEGY_STARTING_EQUITY;
+
+        let period_returns: Vec<f64> = equity_curve.windows(2)
+            .map(|w| if w[0] != 0.0 { (w[1] - w[0]) / w[0] } else { 0.0 })
+            .collect();
+        let sharpe_ratio = calculate_sharpe_ratio(&period_returns, 0.0);
+        let max_drawdown = calculate_max_drawdown(&equity_curve);
+
+        let winning_trades = trades.iter().filter(|t| t.return_pct > 0.0).count();
+        let win_rate = if !trades.is_empty() { winning_trades as f64 / trades.len() as f64 } else { 0.0 };
+
+        let avg_trade_duration_days = if !trades.is_empty() {
+            trades.iter()
+                .map(|t| (t.exit_date - t.entry_date).num_days() as f64)
+                .sum::<f64>() / trades.len() as f64
+        } else {
+            0.0
+        };
+
+        info!(
+            "✅ Strategy backtest complete for {}: total_return {:.2}% vs benchmark {:.2}%",
+            ticker, total_return * 100.0, benchmark_return * 100.0,
+        );
+
+        Ok(StrategyBacktestResult {
+            equity_curve,
+            trades,
+            total_return,
+            max_drawdown,
+            sharpe_ratio,
+            benchmark_return,
+            win_rate,
+            avg_trade_duration_days,
+        })
+    }
+
     fn calculate_kelly_fraction(&self, p: f64, g: f64, l: f64) -> Result<f64, TradingBotError> {
         if l == 0.0 {
             return Ok(0.0);
@@ -363,6 +552,23 @@ impl PythonBridge {
         Ok(kelly_fraction.max(0.0))
     }
     
+    pub fn get_candidate_universe(&self) -> Result<Vec<String>, TradingBotError> {
+        info!("🌐 Fetching candidate universe...");
+
+        Python::with_gil(|py| {
+            let modules = self.py_runtime.as_ref().unwrap().bind(py);
+            let main_item = modules.get_item("main").unwrap();
+            let main_module = main_item.downcast::<PyModule>()
+                .map_err(|e| TradingBotError::PythonBridge(format!("Failed to downcast main module: {}", e)))?;
+
+            let result = main_module.call_method0("get_candidate_universe")?;
+            let py_list = result.downcast::<pyo3::types::PyList>()
+                .map_err(|e| TradingBotError::PythonBridge(format!("Expected a list of tickers: {}", e)))?;
+
+            Ok(py_list.iter().map(|t| t.extract::<String>().unwrap_or_default()).collect())
+        })
+    }
+
     pub fn test_python_bridge(&self) -> Result<(), TradingBotError> {
         info!("🧪 Testing Python bridge functionality...");
         
@@ -382,3 +588,43 @@ impl PythonBridge {
         })
     }
 }
+
+/// Builds an aligned `tickers.len() x tickers.len()` sample covariance matrix
+/// from each ticker's daily-return series, trimming every series to the
+/// shortest common length. Tickers with fewer than 2 aligned observations
+/// yield an all-zero matrix (and thus zero portfolio variance), which is the
+/// graceful-degradation behavior the rest of this module follows.
+pub fn covariance_matrix(tickers: &[String], returns_by_ticker: &HashMap<String, Vec<f64>>) -> Vec<Vec<f64>> {
+    let n = tickers.len();
+    let mut matrix = vec![vec![0.0; n]; n];
+
+    let min_len = tickers.iter()
+        .map(|t| returns_by_ticker.get(t).map(|r| r.len()).unwrap_or(0))
+        .min()
+        .unwrap_or(0);
+
+    if min_len < 2 {
+        return matrix;
+    }
+
+    let aligned: Vec<&[f64]> = tickers.iter()
+        .map(|t| {
+            let series = &returns_by_ticker[t];
+            &series[series.len() - min_len..]
+        })
+        .collect();
+
+    let means: Vec<f64> = aligned.iter()
+        .map(|series| series.iter().sum::<f64>() / min_len as f64)
+        .collect();
+
+    for i in 0..n {
+        for j in 0..n {
+            matrix[i][j] = (0..min_len)
+                .map(|k| (aligned[i][k] - means[i]) * (aligned[j][k] - means[j]))
+                .sum::<f64>() / (min_len - 1) as f64;
+        }
+    }
+
+    matrix
+}
@@ -0,0 +1,167 @@
+use crate::error::TradingBotError;
+use log::info;
+use std::collections::{HashMap, HashSet};
+
+/// A currently-held position expressed in shares, as tracked by a broker or
+/// portfolio ledger rather than by the one-shot Kelly allocation.
+#[derive(Debug, Clone)]
+pub struct Holding {
+    pub ticker: String,
+    pub shares: f64,
+    pub price: f64,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradeDirection {
+    Buy,
+    Sell,
+    Hold,
+}
+
+#[derive(Debug, Clone)]
+pub struct RebalanceTrade {
+    pub ticker: String,
+    pub delta_shares: i64,
+    pub direction: TradeDirection,
+    pub post_rebalance_value: f64,
+}
+
+#[derive(Debug, Clone)]
+pub struct RebalanceResult {
+    pub trades: Vec<RebalanceTrade>,
+    pub cash_remaining: f64,
+}
+
+const MAX_REDISTRIBUTION_ITERATIONS: usize = 50;
+const OVERFLOW_EPSILON: f64 = 1e-6;
+
+/// Turns `holdings` plus per-asset `target_weights` into concrete buy/sell
+/// trades using the classic three-pass rebalancing algorithm: clamp bounds
+/// bottom-up, distribute investable cash top-down (redistributing overflow
+/// from capped assets), then round to whole shares bottom-up.
+pub fn rebalance_portfolio(
+    holdings: &[Holding],
+    cash: f64,
+    target_weights: &[(String, f64)],
+    min_weight: f64,
+    max_weight: f64,
+    min_cash_reserve: f64,
+    min_trade_volume: f64,
+) -> Result<RebalanceResult, TradingBotError> {
+    info!("⚖️ Rebalancing {} positions against {} targets...", holdings.len(), target_weights.len());
+
+    if target_weights.is_empty() {
+        return Err(TradingBotError::Calculation("No target weights provided".to_string()));
+    }
+
+    let prices: HashMap<&str, f64> = holdings.iter().map(|h| (h.ticker.as_str(), h.price)).collect();
+    let current_value: HashMap<&str, f64> = holdings.iter()
+        .map(|h| (h.ticker.as_str(), h.shares * h.price))
+        .collect();
+
+    let target_net_value = current_value.values().sum::<f64>() + cash;
+    if target_net_value <= 0.0 {
+        return Err(TradingBotError::Calculation("Target net value must be positive".to_string()));
+    }
+
+    // Pass 1 (bottom-up): strict per-asset dollar bounds.
+    let bounds: HashMap<&str, (f64, f64)> = target_weights.iter()
+        .map(|(ticker, _)| (ticker.as_str(), (min_weight * target_net_value, max_weight * target_net_value)))
+        .collect();
+
+    // Pass 2 (top-down): distribute investable cash proportionally to weights,
+    // redistributing overflow from capped assets to assets with headroom.
+    let investable = (target_net_value - min_cash_reserve).max(0.0);
+    let mut alloc: HashMap<&str, f64> = target_weights.iter()
+        .map(|(ticker, weight)| (ticker.as_str(), weight * investable))
+        .collect();
+    let mut locked: HashSet<&str> = HashSet::new();
+
+    for _ in 0..MAX_REDISTRIBUTION_ITERATIONS {
+        let mut overflow = 0.0;
+
+        for (ticker, _) in target_weights {
+            if locked.contains(ticker.as_str()) {
+                continue;
+            }
+            let (min_dollar, max_dollar) = bounds[ticker.as_str()];
+            let value = alloc[ticker.as_str()];
+
+            if value > max_dollar {
+                overflow += value - max_dollar;
+                alloc.insert(ticker.as_str(), max_dollar);
+                locked.insert(ticker.as_str());
+            } else if value < min_dollar {
+                overflow += value - min_dollar;
+                alloc.insert(ticker.as_str(), min_dollar);
+                locked.insert(ticker.as_str());
+            }
+        }
+
+        if overflow.abs() < OVERFLOW_EPSILON {
+            break;
+        }
+
+        let unlocked_weight: f64 = target_weights.iter()
+            .filter(|(ticker, _)| !locked.contains(ticker.as_str()))
+            .map(|(_, w)| w)
+            .sum();
+
+        if unlocked_weight <= 0.0 {
+            break;
+        }
+
+        for (ticker, weight) in target_weights {
+            if !locked.contains(ticker.as_str()) {
+                *alloc.get_mut(ticker.as_str()).unwrap() += overflow * (weight / unlocked_weight);
+            }
+        }
+    }
+
+    // Pass 3 (bottom-up): round to whole shares, suppress dust trades, settle cash.
+    let mut trades = Vec::with_capacity(target_weights.len());
+    let mut invested_value = 0.0;
+
+    for (ticker, _) in target_weights {
+        let price = *prices.get(ticker.as_str()).unwrap_or(&0.0);
+        if price <= 0.0 {
+            continue;
+        }
+
+        let current = *current_value.get(ticker.as_str()).unwrap_or(&0.0);
+        let target_value = alloc[ticker.as_str()];
+        let delta_value = target_value - current;
+
+        if delta_value.abs() < min_trade_volume {
+            invested_value += current;
+            trades.push(RebalanceTrade {
+                ticker: ticker.clone(),
+                delta_shares: 0,
+                direction: TradeDirection::Hold,
+                post_rebalance_value: current,
+            });
+            continue;
+        }
+
+        let delta_shares = (delta_value / price).round() as i64;
+        let post_value = current + delta_shares as f64 * price;
+        invested_value += post_value;
+
+        trades.push(RebalanceTrade {
+            ticker: ticker.clone(),
+            delta_shares,
+            direction: if delta_shares > 0 {
+                TradeDirection::Buy
+            } else if delta_shares < 0 {
+                TradeDirection::Sell
+            } else {
+                TradeDirection::Hold
+            },
+            post_rebalance_value: post_value,
+        });
+    }
+
+    let cash_remaining = target_net_value - invested_value;
+
+    Ok(RebalanceResult { trades, cash_remaining })
+}
@@ -0,0 +1,74 @@
+use crate::{
+    error::TradingBotError,
+    execution::{OrderRequest, OrderSide, OrderType, TimeInForce},
+};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LadderMode {
+    /// Equal size at every rung.
+    Flat,
+    /// Size grows linearly from the low end of the range to the high end.
+    Linear,
+    /// Constant-product style sizing: more size at lower prices, mirroring
+    /// how an xy=k AMM curve concentrates liquidity as price falls.
+    Xyk,
+}
+
+/// A linear ladder of resting limit orders spanning `[low, high]`, used to
+/// replicate passive liquidity provision across a price range.
+pub struct LiquidityLadder {
+    pub ticker: String,
+    pub low: f64,
+    pub high: f64,
+    pub rungs: usize,
+    pub mode: LadderMode,
+}
+
+impl LiquidityLadder {
+    pub fn new(ticker: String, low: f64, high: f64, rungs: usize, mode: LadderMode) -> Result<Self, TradingBotError> {
+        if rungs == 0 {
+            return Err(TradingBotError::Calculation("Ladder must have at least one rung".to_string()));
+        }
+        if low <= 0.0 || high <= low {
+            return Err(TradingBotError::Calculation("Ladder requires 0 < low < high".to_string()));
+        }
+
+        Ok(Self { ticker, low, high, rungs, mode })
+    }
+
+    /// Builds `rungs` evenly spaced limit orders between `low` and `high`,
+    /// distributing `total_quantity` across them per `mode`.
+    pub fn build_orders(&self, total_quantity: u32, side: OrderSide) -> Vec<OrderRequest> {
+        let step_divisor = (self.rungs - 1).max(1) as f64;
+        let prices: Vec<f64> = (0..self.rungs)
+            .map(|i| self.low + (self.high - self.low) * i as f64 / step_divisor)
+            .collect();
+
+        let weights: Vec<f64> = match self.mode {
+            LadderMode::Flat => vec![1.0; self.rungs],
+            LadderMode::Linear => (0..self.rungs).map(|i| (i + 1) as f64).collect(),
+            LadderMode::Xyk => prices.iter().map(|p| 1.0 / p.max(1e-9)).collect(),
+        };
+
+        let weight_sum: f64 = weights.iter().sum();
+
+        prices.into_iter().zip(weights.into_iter())
+            .map(|(price, weight)| {
+                let quantity = if weight_sum > 0.0 {
+                    ((total_quantity as f64) * (weight / weight_sum)).round() as u32
+                } else {
+                    0
+                };
+
+                OrderRequest {
+                    ticker: self.ticker.clone(),
+                    side,
+                    quantity,
+                    order_type: OrderType::Limit(price),
+                    time_in_force: TimeInForce::GoodTilCanceled,
+                    reference_price: price,
+                }
+            })
+            .collect()
+    }
+}
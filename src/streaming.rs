@@ -0,0 +1,216 @@
+use crate::error::TradingBotError;
+use serde_json::Value;
+use std::collections::{HashMap, VecDeque};
+use std::time::Duration;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Market {
+    Stock,
+    Crypto,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MessageType {
+    Trade,
+    L2Event,
+    L2Snapshot,
+    Bbo,
+    Ticker,
+    Candlestick,
+    FundingRate,
+}
+
+/// A single normalized message from a market-data feed, independent of the
+/// exchange's wire format.
+#[derive(Debug, Clone)]
+pub struct ParsedMessage {
+    pub exchange: String,
+    pub symbol: String,
+    pub pair: String,
+    pub msg_type: MessageType,
+    pub timestamp_ms: i64,
+    pub payload: Value,
+}
+
+/// Bounded rolling window of period returns, computed from successive prices,
+/// that feeds `StockData.returns`/`CryptoData` without unbounded growth.
+#[derive(Debug, Clone)]
+struct ReturnsWindow {
+    prices: VecDeque<f64>,
+    returns: VecDeque<f64>,
+    capacity: usize,
+}
+
+impl ReturnsWindow {
+    fn new(capacity: usize) -> Self {
+        Self {
+            prices: VecDeque::with_capacity(capacity),
+            returns: VecDeque::with_capacity(capacity),
+            capacity,
+        }
+    }
+
+    fn push_price(&mut self, price: f64) {
+        if let Some(&last) = self.prices.back() {
+            if last > 0.0 {
+                if self.returns.len() == self.capacity {
+                    self.returns.pop_front();
+                }
+                self.returns.push_back((price - last) / last);
+            }
+        }
+        if self.prices.len() == self.capacity {
+            self.prices.pop_front();
+        }
+        self.prices.push_back(price);
+    }
+
+    fn returns(&self) -> Vec<f64> {
+        self.returns.iter().copied().collect()
+    }
+}
+
+/// A single price-level order book that follows exchange snapshot+diff
+/// ordering: diffs are rejected until a snapshot establishes a baseline, and
+/// a zero-quantity diff level removes that price level.
+#[derive(Debug, Clone, Default)]
+pub struct OrderBook {
+    bids: Vec<(f64, f64)>,
+    asks: Vec<(f64, f64)>,
+    has_snapshot: bool,
+}
+
+impl OrderBook {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn apply_snapshot(&mut self, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) {
+        self.bids = bids;
+        self.asks = asks;
+        self.sort();
+        self.has_snapshot = true;
+    }
+
+    pub fn apply_diff(&mut self, bids: Vec<(f64, f64)>, asks: Vec<(f64, f64)>) -> Result<(), TradingBotError> {
+        if !self.has_snapshot {
+            return Err(TradingBotError::DataProcessing("Dropped L2 diff received before snapshot".to_string()));
+        }
+        Self::merge_levels(&mut self.bids, bids);
+        Self::merge_levels(&mut self.asks, asks);
+        self.sort();
+        Ok(())
+    }
+
+    fn merge_levels(book_side: &mut Vec<(f64, f64)>, updates: Vec<(f64, f64)>) {
+        for (price, qty) in updates {
+            book_side.retain(|&(p, _)| p != price);
+            if qty > 0.0 {
+                book_side.push((price, qty));
+            }
+        }
+    }
+
+    fn sort(&mut self) {
+        self.bids.sort_by(|a, b| b.0.partial_cmp(&a.0).unwrap());
+        self.asks.sort_by(|a, b| a.0.partial_cmp(&b.0).unwrap());
+    }
+
+    pub fn best_bid(&self) -> Option<(f64, f64)> {
+        self.bids.first().copied()
+    }
+
+    pub fn best_ask(&self) -> Option<(f64, f64)> {
+        self.asks.first().copied()
+    }
+}
+
+/// Exponential backoff with a cap, used to space out reconnect attempts when
+/// a market-data connection drops.
+#[derive(Debug, Clone)]
+pub struct ReconnectBackoff {
+    attempt: u32,
+    base: Duration,
+    max: Duration,
+}
+
+impl ReconnectBackoff {
+    pub fn new(base: Duration, max: Duration) -> Self {
+        Self { attempt: 0, base, max }
+    }
+
+    pub fn next_delay(&mut self) -> Duration {
+        let factor = 2u32.saturating_pow(self.attempt.min(16));
+        self.attempt += 1;
+        (self.base * factor).min(self.max)
+    }
+
+    pub fn reset(&mut self) {
+        self.attempt = 0;
+    }
+}
+
+/// Ingests normalized market-data messages and maintains rolling returns
+/// windows and order books per symbol. Transport-agnostic: any feed (a real
+/// exchange websocket client, or the polling fallback in `TradingBot`) can
+/// parse its wire format into `ParsedMessage` and hand it to `ingest`.
+pub struct StreamManager {
+    windows: HashMap<String, ReturnsWindow>,
+    books: HashMap<String, OrderBook>,
+    window_capacity: usize,
+}
+
+impl StreamManager {
+    pub fn new(window_capacity: usize) -> Self {
+        Self {
+            windows: HashMap::new(),
+            books: HashMap::new(),
+            window_capacity,
+        }
+    }
+
+    pub fn ingest(&mut self, message: &ParsedMessage) -> Result<(), TradingBotError> {
+        match message.msg_type {
+            MessageType::Trade | MessageType::Ticker | MessageType::Bbo | MessageType::Candlestick => {
+                if let Some(price) = message.payload.get("price").and_then(|p| p.as_f64()) {
+                    self.windows.entry(message.symbol.clone())
+                        .or_insert_with(|| ReturnsWindow::new(self.window_capacity))
+                        .push_price(price);
+                }
+                Ok(())
+            }
+            MessageType::L2Snapshot => {
+                let bids = Self::extract_levels(&message.payload, "bids");
+                let asks = Self::extract_levels(&message.payload, "asks");
+                self.books.entry(message.symbol.clone()).or_insert_with(OrderBook::new).apply_snapshot(bids, asks);
+                Ok(())
+            }
+            MessageType::L2Event => {
+                let bids = Self::extract_levels(&message.payload, "bids");
+                let asks = Self::extract_levels(&message.payload, "asks");
+                self.books.entry(message.symbol.clone()).or_insert_with(OrderBook::new).apply_diff(bids, asks)
+            }
+            MessageType::FundingRate => Ok(()),
+        }
+    }
+
+    fn extract_levels(payload: &Value, key: &str) -> Vec<(f64, f64)> {
+        payload.get(key)
+            .and_then(|v| v.as_array())
+            .map(|levels| levels.iter()
+                .filter_map(|level| {
+                    let pair = level.as_array()?;
+                    Some((pair.first()?.as_f64()?, pair.get(1)?.as_f64()?))
+                })
+                .collect())
+            .unwrap_or_default()
+    }
+
+    pub fn returns(&self, symbol: &str) -> Vec<f64> {
+        self.windows.get(symbol).map(|w| w.returns()).unwrap_or_default()
+    }
+
+    pub fn order_book(&self, symbol: &str) -> Option<&OrderBook> {
+        self.books.get(symbol)
+    }
+}
@@ -0,0 +1,114 @@
+use crate::models::PortfolioSummary;
+use std::collections::HashMap;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TradingStatus {
+    Trading,
+    Halted,
+    Delisted,
+}
+
+/// Exchange-reported metadata that bounds how an order for a symbol may be sized.
+#[derive(Debug, Clone)]
+pub struct SymbolInfo {
+    pub ticker: String,
+    /// Smallest allowed increment of shares/contracts per order.
+    pub lot_size_step: f64,
+    /// Minimum allowed notional value (price * quantity) per order.
+    pub min_notional: f64,
+    /// Smallest allowed increment of price.
+    pub price_tick: f64,
+    pub status: TradingStatus,
+}
+
+impl SymbolInfo {
+    /// Rounds a raw share count down to the nearest valid lot-size step.
+    pub fn round_to_lot(&self, quantity: f64) -> u32 {
+        if self.lot_size_step <= 0.0 {
+            return quantity.floor().max(0.0) as u32;
+        }
+        ((quantity / self.lot_size_step).floor() * self.lot_size_step).max(0.0) as u32
+    }
+
+    /// Rounds a price to the nearest valid tick.
+    pub fn round_to_tick(&self, price: f64) -> f64 {
+        if self.price_tick <= 0.0 {
+            return price;
+        }
+        (price / self.price_tick).round() * self.price_tick
+    }
+}
+
+/// Registry of per-symbol exchange metadata, keyed by ticker.
+pub struct SymbolMetadataRegistry {
+    info: HashMap<String, SymbolInfo>,
+}
+
+impl SymbolMetadataRegistry {
+    pub fn new() -> Self {
+        Self { info: HashMap::new() }
+    }
+
+    pub fn insert(&mut self, info: SymbolInfo) {
+        self.info.insert(info.ticker.clone(), info);
+    }
+
+    pub fn get(&self, ticker: &str) -> Option<&SymbolInfo> {
+        self.info.get(ticker)
+    }
+}
+
+impl Default for SymbolMetadataRegistry {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+/// Applies exchange symbol constraints to a portfolio summary: non-tradeable
+/// symbols are zeroed out and noted, remaining allocations are rounded to a
+/// valid lot size, and allocations that fall below the minimum notional are
+/// dropped. Totals are recomputed from the adjusted allocations. Symbols with
+/// no registered metadata are passed through unchanged.
+pub fn apply_symbol_constraints(mut summary: PortfolioSummary, registry: &SymbolMetadataRegistry) -> PortfolioSummary {
+    let original_total_allocated = summary.total_allocated;
+    let mut below_min_notional = Vec::new();
+
+    for allocation in &mut summary.allocations {
+        let info = match registry.get(&allocation.ticker) {
+            Some(info) => info,
+            None => continue,
+        };
+
+        if info.status != TradingStatus::Trading {
+            allocation.reasons.push(format!("⛔ Skipped: {} is not currently tradeable ({:?})", allocation.ticker, info.status));
+            allocation.shares_to_buy = 0;
+            allocation.dollar_allocation = 0.0;
+            continue;
+        }
+
+        let rounded_shares = info.round_to_lot(allocation.shares_to_buy as f64);
+        let rounded_price = info.round_to_tick(allocation.current_price);
+        let notional = rounded_shares as f64 * rounded_price;
+
+        if notional < info.min_notional {
+            allocation.reasons.push(format!(
+                "⛔ Skipped: ${:.2} notional is below the ${:.2} minimum for {}",
+                notional, info.min_notional, allocation.ticker
+            ));
+            allocation.shares_to_buy = 0;
+            allocation.dollar_allocation = 0.0;
+            below_min_notional.push(allocation.ticker.clone());
+            continue;
+        }
+
+        allocation.shares_to_buy = rounded_shares;
+        allocation.dollar_allocation = notional;
+    }
+
+    summary.allocations.retain(|a| !below_min_notional.contains(&a.ticker));
+    summary.total_allocated = summary.allocations.iter().map(|a| a.dollar_allocation).sum();
+    summary.number_of_positions = summary.allocations.iter().filter(|a| a.shares_to_buy > 0).count();
+    summary.cash_remaining += original_total_allocated - summary.total_allocated;
+
+    summary
+}
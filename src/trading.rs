@@ -6,10 +6,31 @@ use crate::{
     kelly::KellyAnalyzer,
     options::OptionsAnalyzer,
     crypto::CryptoAnalyzer,
-    portfolio::PortfolioManager,
+    portfolio::{AllocationAdjustment, HeldPosition, PortfolioManager},
+    notifier::{WebhookNotifier, NotifyEvent},
+    momentum::MomentumSelector,
+    streaming::{Market, MessageType, ParsedMessage, ReconnectBackoff, StreamManager},
+    execution::{Broker, ExecutionReport, LiveBroker, OrderRequest, OrderSide, PaperBroker},
+    strategies::{LadderMode, LiquidityLadder},
+    symbols::{self, SymbolMetadataRegistry},
+    backtest::{display_backtest_report, Backtester, TradeStatsReport},
+    performance::{PerformanceAnalysis, PerformanceAnalyzer, Position},
+    rebalance::{Holding, RebalanceResult},
+    hyperopt::{self, Objective, Optimizer, TrialResult},
+    ga_optimizer::{GaBasket, GaPortfolioOptimizer, OptionCandidate},
+    data_provider::{AlphaVantageProvider, DataProvider, DataProviderRegistry, FinnhubProvider, TwelveDataProvider},
+    protections::{CooldownPeriod, MaxDrawdownProtection, ProtectionLock, StoplossGuard, TradeRecord},
 };
+use std::collections::HashMap;
+use std::sync::Mutex;
 use log::{info, warn};
-use chrono::Utc;
+use chrono::{DateTime, Utc};
+use std::time::Duration;
+
+/// Bounds for how much history the protection guards look back over, so the
+/// in-memory ledgers below don't grow unbounded across a long-running process.
+const MAX_EQUITY_HISTORY: usize = 500;
+const MAX_TRADE_HISTORY: usize = 500;
 
 pub struct TradingBot {
     config: Config,
@@ -18,17 +39,62 @@ pub struct TradingBot {
     options_analyzer: OptionsAnalyzer,
     crypto_analyzer: CryptoAnalyzer,
     portfolio_manager: PortfolioManager,
+    notifier: WebhookNotifier,
+    symbol_registry: SymbolMetadataRegistry,
+    performance_analyzer: PerformanceAnalyzer,
+    ga_optimizer: GaPortfolioOptimizer,
+    data_provider_registry: DataProviderRegistry,
+    http_client: reqwest::Client,
+    stoploss_guard: StoplossGuard,
+    max_drawdown_protection: MaxDrawdownProtection,
+    cooldown_period: CooldownPeriod,
+    /// Trailing total-portfolio-value history, appended to on every
+    /// `calculate_portfolio_allocation` call, consulted by `max_drawdown_protection`.
+    equity_curve: Mutex<Vec<f64>>,
+    /// Closed-trade ledger consulted by `stoploss_guard`/`cooldown_period`.
+    /// Nothing populates this automatically yet - this crate's execution path
+    /// only ever opens positions - so callers that track fills against a real
+    /// broker should feed closes in via `record_closed_trade`.
+    trade_history: Mutex<Vec<TradeRecord>>,
 }
 
 impl TradingBot {
     pub fn new(config: Config, python_bridge: PythonBridge) -> Result<Self, TradingBotError> {
         info!("🤖 Initializing Trading Bot...");
-        
+
         let kelly_analyzer = KellyAnalyzer::new(config.trading.clone());
         let options_analyzer = OptionsAnalyzer::new(config.trading.clone());
         let crypto_analyzer = CryptoAnalyzer::new(config.api.clone());
         let portfolio_manager = PortfolioManager::new(config.trading.clone());
-        
+        let notifier = WebhookNotifier::new(config.notifications.clone());
+        let symbol_registry = SymbolMetadataRegistry::new();
+        let performance_analyzer = PerformanceAnalyzer::new();
+        let ga_optimizer = GaPortfolioOptimizer::new(config.ga_optimizer.clone());
+
+        // Only the providers an operator has actually enabled (and given an
+        // API key) take part in failover; with none enabled this degrades to
+        // a no-op registry and `analyze_stock_potential` stays on the Python
+        // bridge price alone.
+        let mut providers: Vec<Box<dyn DataProvider>> = Vec::new();
+        if config.data_layer.alpha_vantage.enabled {
+            providers.push(Box::new(AlphaVantageProvider::new(config.data_layer.alpha_vantage.clone())));
+        }
+        if config.data_layer.finnhub.enabled {
+            providers.push(Box::new(FinnhubProvider::new(config.data_layer.finnhub.clone())));
+        }
+        if config.data_layer.twelve_data.enabled {
+            providers.push(Box::new(TwelveDataProvider::new(config.data_layer.twelve_data.clone())));
+        }
+        let data_provider_registry = DataProviderRegistry::new(
+            providers,
+            config.data_layer.cache_expire_seconds,
+            config.data_layer.global_rate_limit_per_minute,
+        );
+        let http_client = reqwest::Client::new();
+        let stoploss_guard = StoplossGuard::new(config.protections.clone());
+        let max_drawdown_protection = MaxDrawdownProtection::new(config.protections.clone());
+        let cooldown_period = CooldownPeriod::new(config.protections.clone());
+
         Ok(Self {
             config,
             python_bridge,
@@ -36,8 +102,25 @@ impl TradingBot {
             options_analyzer,
             crypto_analyzer,
             portfolio_manager,
+            notifier,
+            symbol_registry,
+            performance_analyzer,
+            ga_optimizer,
+            data_provider_registry,
+            http_client,
+            stoploss_guard,
+            max_drawdown_protection,
+            cooldown_period,
+            equity_curve: Mutex::new(Vec::new()),
+            trade_history: Mutex::new(Vec::new()),
         })
     }
+
+    /// Registers exchange metadata (lot size, min notional, tick size, trading
+    /// status) used to size and filter allocations for a symbol.
+    pub fn register_symbol(&mut self, info: symbols::SymbolInfo) {
+        self.symbol_registry.insert(info);
+    }
     
     pub async fn run_complete_analysis(
         &mut self,
@@ -105,9 +188,13 @@ impl TradingBot {
         // Step 4: Portfolio allocation
         let portfolio_summary = if !stock_analysis.is_empty() {
             match self.calculate_portfolio_allocation(&stock_analysis, portfolio_value, scaling_factor).await {
-                Ok(summary) => Some(summary),
+                Ok(summary) => {
+                    self.notifier.send_best_effort(NotifyEvent::PortfolioSummaryReady(summary.clone())).await;
+                    Some(summary)
+                }
                 Err(e) => {
                     warn!("Failed to calculate portfolio allocation: {}", e);
+                    self.notifier.send_best_effort(NotifyEvent::Error(e.to_string())).await;
                     None
                 }
             }
@@ -153,10 +240,24 @@ impl TradingBot {
     
     pub async fn analyze_stock_potential(&self, ticker: &str) -> Result<StockData, TradingBotError> {
         info!("📈 Analyzing stock potential for {}", ticker);
-        
+
         // Use Python bridge to get stock data
-        let stock_data = self.python_bridge.analyze_stock_potential(ticker)?;
-        
+        let mut stock_data = self.python_bridge.analyze_stock_potential(ticker)?;
+
+        // Overlay a fresher price from the resilient, cached, failover data
+        // layer when at least one provider is enabled; keep the Python
+        // bridge's price (and its returns/volatility, which the data layer
+        // doesn't supply) if every provider is disabled or unreachable.
+        match self.data_provider_registry.get_quote(&self.http_client, ticker, "1d").await {
+            Ok(quote) => {
+                info!("📡 Using data-layer price for {} (${:.2})", ticker, quote.current_price);
+                stock_data.current_price = quote.current_price;
+            }
+            Err(e) => {
+                warn!("Data layer unavailable for {}, keeping Python bridge price: {}", ticker, e);
+            }
+        }
+
         Ok(stock_data)
     }
     
@@ -171,7 +272,17 @@ impl TradingBot {
         
         Ok(enhanced_options)
     }
-    
+
+    /// Searches for a near-optimal basket of cash-secured `options` positions
+    /// under a fixed collateral `budget` via the genetic-algorithm optimizer,
+    /// using each option's already-computed implied volatility.
+    pub fn optimize_options_basket(&self, options: &[OptionsAnalysis], budget: f64) -> Result<GaBasket, TradingBotError> {
+        let candidates: Vec<OptionCandidate> = options.iter()
+            .map(|o| OptionCandidate::from_option(o, o.implied_volatility.unwrap_or(0.3)))
+            .collect();
+        self.ga_optimizer.optimize(&candidates, budget)
+    }
+
     pub async fn analyze_crypto(&self) -> Result<CryptoAnalysis, TradingBotError> {
         info!("🪙 Analyzing crypto market...");
         
@@ -205,45 +316,328 @@ impl TradingBot {
             scaling_factor,
         )?;
         
+        // Evaluate the protection guards against the trailing equity/trade
+        // history and fold any resulting locks into the enhanced summary.
+        let now = Utc::now();
+        let locks = self.evaluate_protection_locks(now);
+
         // Enhance with Rust-based portfolio management
-        let enhanced_summary = self.portfolio_manager.enhance_portfolio_summary(portfolio_summary)?;
-        
-        Ok(enhanced_summary)
+        let enhanced_summary = self.portfolio_manager.enhance_portfolio_summary(portfolio_summary, &locks)?;
+        let constrained_summary = symbols::apply_symbol_constraints(enhanced_summary, &self.symbol_registry);
+
+        let mut equity_curve = self.equity_curve.lock().unwrap();
+        equity_curve.push(constrained_summary.total_allocated + constrained_summary.cash_remaining);
+        if equity_curve.len() > MAX_EQUITY_HISTORY {
+            let overflow = equity_curve.len() - MAX_EQUITY_HISTORY;
+            equity_curve.drain(0..overflow);
+        }
+
+        Ok(constrained_summary)
     }
-    
+
+    /// Runs every protection guard against the trailing equity curve and
+    /// closed-trade ledger, returning every active lock.
+    fn evaluate_protection_locks(&self, now: DateTime<Utc>) -> Vec<ProtectionLock> {
+        let trade_history = self.trade_history.lock().unwrap();
+        let equity_curve = self.equity_curve.lock().unwrap();
+
+        let mut locks = Vec::new();
+        locks.extend(self.stoploss_guard.evaluate(&trade_history, now));
+        locks.extend(self.max_drawdown_protection.evaluate(&equity_curve, now));
+        locks.extend(self.cooldown_period.evaluate(&trade_history, now));
+        locks
+    }
+
+    /// Records that `asset` closed at `closed_at`, `is_loss` or not, so the
+    /// next `calculate_portfolio_allocation` call's `StoplossGuard`/
+    /// `CooldownPeriod` evaluation sees it. This crate's own execution path
+    /// only opens positions, so integrating against a real broker's fills is
+    /// the caller's responsibility.
+    pub fn record_closed_trade(&self, asset: &str, closed_at: DateTime<Utc>, is_loss: bool) {
+        let mut trade_history = self.trade_history.lock().unwrap();
+        trade_history.push(TradeRecord { asset: asset.to_string(), closed_at, is_loss });
+        if trade_history.len() > MAX_TRADE_HISTORY {
+            let overflow = trade_history.len() - MAX_TRADE_HISTORY;
+            trade_history.drain(0..overflow);
+        }
+    }
+
+    /// Replays an EWMA trend-following rule against `ticker`'s historical bars
+    /// and compares it to buy-and-hold over the same window.
+    pub async fn backtest_strategy(
+        &self,
+        ticker: &str,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+        timeframe: &str,
+    ) -> Result<StrategyBacktestResult, TradingBotError> {
+        info!("🧪 Backtesting trend strategy for {}", ticker);
+        self.python_bridge.backtest_strategy(ticker, start, end, timeframe)
+    }
+
+    /// Runs a bar-by-bar backtest once per `candidates` scaling factor and
+    /// returns the winner, scored by Sharpe ratio with lower max drawdown
+    /// breaking ties.
+    pub async fn optimize_scaling_factor(
+        &self,
+        stocks_data: Vec<StockData>,
+        portfolio_value: f64,
+        candidates: Vec<f64>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<ScalingOptimizationResult, TradingBotError> {
+        info!("🔬 Sweeping {} scaling-factor candidates...", candidates.len());
+
+        let mut price_history = HashMap::new();
+        for stock in &stocks_data {
+            match self.python_bridge.fetch_price_history(&stock.ticker, start, end, "1d") {
+                Ok(bars) => { price_history.insert(stock.ticker.clone(), bars); }
+                Err(e) => warn!("Failed to fetch price history for {}: {}", stock.ticker, e),
+            }
+        }
+
+        let backtester = Backtester::new(self.config.trading.clone());
+        let mut trials = Vec::with_capacity(candidates.len());
+
+        for &scaling_factor in &candidates {
+            let allocation = self.python_bridge.calculate_portfolio_allocation(
+                stocks_data.clone(),
+                portfolio_value,
+                scaling_factor,
+            )?;
+
+            let result = backtester.run(&allocation.allocations, &price_history)?;
+            let total_return = if result.starting_equity > 0.0 {
+                (result.final_equity - result.starting_equity) / result.starting_equity
+            } else {
+                0.0
+            };
+
+            trials.push(ScalingFactorTrial {
+                scaling_factor,
+                total_return,
+                sharpe_ratio: result.realized_sharpe,
+                max_drawdown: result.realized_max_drawdown,
+            });
+        }
+
+        let best_scaling_factor = trials.iter()
+            .max_by(|a, b| {
+                a.sharpe_ratio.partial_cmp(&b.sharpe_ratio)
+                    .unwrap()
+                    .then(b.max_drawdown.partial_cmp(&a.max_drawdown).unwrap())
+            })
+            .map(|t| t.scaling_factor)
+            .unwrap_or(self.config.trading.default_scaling_factor);
+
+        Ok(ScalingOptimizationResult { best_scaling_factor, trials })
+    }
+
+    /// Replays `stocks_data`'s allocation against its own historical bars,
+    /// rebalancing every `rebalance_every_days` days (or never, if `None`),
+    /// and prints the resulting `TradeStatsReport` through the same
+    /// console-table style as `display_analysis_results`.
+    pub async fn run_backtest_report(
+        &self,
+        stocks_data: Vec<StockData>,
+        portfolio_value: f64,
+        scaling_factor: f64,
+        rebalance_every_days: Option<usize>,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<TradeStatsReport, TradingBotError> {
+        info!("🧪 Running backtest report over {} tickers...", stocks_data.len());
+
+        let mut price_history = HashMap::new();
+        for stock in &stocks_data {
+            match self.python_bridge.fetch_price_history(&stock.ticker, start, end, "1d") {
+                Ok(bars) => { price_history.insert(stock.ticker.clone(), bars); }
+                Err(e) => warn!("Failed to fetch price history for {}: {}", stock.ticker, e),
+            }
+        }
+
+        let allocation = self.python_bridge.calculate_portfolio_allocation(
+            stocks_data,
+            portfolio_value,
+            scaling_factor,
+        )?;
+
+        let backtester = Backtester::new(self.config.trading.clone());
+        let result = match rebalance_every_days {
+            Some(days) => backtester.run_with_rebalancing(&allocation.allocations, &price_history, days)?,
+            None => backtester.run(&allocation.allocations, &price_history)?,
+        };
+
+        let report = backtester.generate_report(&result);
+        display_backtest_report(&report);
+
+        Ok(report)
+    }
+
+    /// Tunes `TradingConfig` fields named in `hyperopt::parameter_space` by
+    /// replaying `batches` rounds of `trials_per_batch` random configs against
+    /// a backtest of `stocks_data`'s allocation, narrowing ranges around the
+    /// best-so-far candidate after each round. Returns every trial, best first.
+    pub async fn optimize_hyperparameters(
+        &self,
+        stocks_data: Vec<StockData>,
+        portfolio_value: f64,
+        objective: Objective,
+        batches: usize,
+        trials_per_batch: usize,
+        start: DateTime<Utc>,
+        end: DateTime<Utc>,
+    ) -> Result<Vec<TrialResult>, TradingBotError> {
+        info!("🔬 Tuning hyperparameters over {} batches of {} trials...", batches, trials_per_batch);
+
+        let mut price_history = HashMap::new();
+        for stock in &stocks_data {
+            match self.python_bridge.fetch_price_history(&stock.ticker, start, end, "1d") {
+                Ok(bars) => { price_history.insert(stock.ticker.clone(), bars); }
+                Err(e) => warn!("Failed to fetch price history for {}: {}", stock.ticker, e),
+            }
+        }
+
+        let allocation = self.python_bridge.calculate_portfolio_allocation(
+            stocks_data,
+            portfolio_value,
+            self.config.trading.default_scaling_factor,
+        )?;
+
+        let optimizer = Optimizer::new(objective);
+        let ranges = hyperopt::parameter_space();
+        optimizer.guided_refinement(&self.config.trading, ranges, &allocation.allocations, &price_history, batches, trials_per_batch)
+    }
+
+    /// Rebalances an already-computed `PortfolioSummary` toward `targets`
+    /// (ticker, target weight), suppressing trades below `min_trade_volume`.
+    /// Unlike `calculate_portfolio_allocation`, this takes an explicit target
+    /// weighting rather than deriving one from fresh stock analysis.
+    pub fn rebalance_portfolio_to_targets(
+        &self,
+        summary: PortfolioSummary,
+        targets: Vec<(String, f64)>,
+        min_trade_volume: f64,
+    ) -> Result<PortfolioSummary, TradingBotError> {
+        self.portfolio_manager.rebalance_to_targets(summary, targets, min_trade_volume)
+    }
+
+    /// Computes incremental DCA-style buy/sell deltas from `held` toward
+    /// `targets`, scaling into rising-conviction names in tranches and
+    /// scaling out when conviction falls or `concentration_risk` breaches
+    /// the configured threshold.
+    pub fn adjust_positions(
+        &self,
+        held: &[HeldPosition],
+        targets: &[PortfolioAllocation],
+        cash_remaining: f64,
+        concentration_risk: f64,
+    ) -> Result<Vec<AllocationAdjustment>, TradingBotError> {
+        self.portfolio_manager.compute_position_adjustments(held, targets, cash_remaining, concentration_risk)
+    }
+
+    /// Rebalances a share-based `holdings` ledger toward `target_weights` using
+    /// the three-pass cash/bounds engine in `rebalance.rs`, rather than the
+    /// dollar-allocation-based `rebalance_to_targets` above.
+    pub fn rebalance_holdings(
+        &self,
+        holdings: &[Holding],
+        cash: f64,
+        target_weights: &[(String, f64)],
+        min_weight: f64,
+        max_weight: f64,
+        min_cash_reserve: f64,
+    ) -> Result<RebalanceResult, TradingBotError> {
+        self.portfolio_manager.rebalance_portfolio(holdings, cash, target_weights, min_weight, max_weight, min_cash_reserve)
+    }
+
+    /// Computes realized IRR, time-weighted return, and per-position P&L for
+    /// a running portfolio of open `positions` plus a ledger of deposit/
+    /// withdrawal `cash_flows`, as of `as_of`.
+    pub fn portfolio_performance(
+        &self,
+        positions: Vec<Position>,
+        cash_flows: Vec<(DateTime<Utc>, f64)>,
+        as_of: DateTime<Utc>,
+    ) -> Result<PerformanceAnalysis, TradingBotError> {
+        self.performance_analyzer.portfolio_performance(positions, cash_flows, as_of)
+    }
+
     async fn get_trending_stocks(&self) -> Result<Vec<String>, TradingBotError> {
-        info!("🔍 Getting trending stocks...");
-        
-        // For now, return a list of popular penny stocks
-        // In a full implementation, you'd scrape trending stocks from various sources
-        let trending_stocks = vec![
-            "SNDL".to_string(),
-            "BITF".to_string(),
-            "HEXO".to_string(),
-            "ACB".to_string(),
-            "TLRY".to_string(),
-            "CGC".to_string(),
-            "APHA".to_string(),
-            "CRON".to_string(),
-            "OGI".to_string(),
-            "VFF".to_string(),
-        ];
-        
-        Ok(trending_stocks)
+        info!("🔍 Selecting trending stocks by momentum...");
+
+        let universe = self.python_bridge.get_candidate_universe().unwrap_or_else(|e| {
+            warn!("Failed to fetch candidate universe, falling back to static list: {}", e);
+            vec![
+                "SNDL".to_string(),
+                "BITF".to_string(),
+                "HEXO".to_string(),
+                "ACB".to_string(),
+                "TLRY".to_string(),
+                "CGC".to_string(),
+                "APHA".to_string(),
+                "CRON".to_string(),
+                "OGI".to_string(),
+                "VFF".to_string(),
+            ]
+        });
+
+        let mut candidates = Vec::new();
+        for ticker in &universe {
+            match self.python_bridge.analyze_stock_potential(ticker) {
+                Ok(stock) => candidates.push((ticker.clone(), stock.returns)),
+                Err(e) => warn!("Failed to fetch returns for {}: {}", ticker, e),
+            }
+        }
+
+        let selector = MomentumSelector::new(self.config.trading.clone());
+        Ok(selector.rank(candidates))
     }
     
+    /// Scores `universe` by trailing total return over `lookback_days`, drops
+    /// anything below `min_trend`, and returns the top `top_n` `StockData`
+    /// entries ready to feed into `calculate_portfolio_allocation`.
+    pub async fn rank_by_momentum(
+        &self,
+        universe: Vec<String>,
+        lookback_days: u32,
+        top_n: usize,
+        min_trend: f64,
+    ) -> Result<Vec<StockData>, TradingBotError> {
+        info!("🏆 Ranking {} candidates by momentum...", universe.len());
+
+        let mut candidates = Vec::new();
+        for ticker in &universe {
+            match self.python_bridge.analyze_stock_potential(ticker) {
+                Ok(stock) => candidates.push(stock),
+                Err(e) => warn!("Failed to fetch data for {}: {}", ticker, e),
+            }
+        }
+
+        let selector = MomentumSelector::new(self.config.trading.clone());
+        Ok(selector.rank_by_momentum(candidates, lookback_days, top_n, min_trend))
+    }
+
     async fn calculate_unified_metrics(
         &self,
         stock_analysis: &[StockData],
         kelly_analysis: &[KellyAnalysis],
     ) -> Result<Vec<UnifiedRiskRewardMetric>, TradingBotError> {
         info!("🧮 Calculating unified risk-reward metrics...");
-        
+
+        let benchmark_returns = match self.python_bridge.analyze_stock_potential(&self.config.trading.benchmark_symbol) {
+            Ok(benchmark_data) => benchmark_data.returns,
+            Err(e) => {
+                warn!("Failed to fetch benchmark data for {}: {}", self.config.trading.benchmark_symbol, e);
+                Vec::new()
+            }
+        };
+
         let mut unified_metrics = Vec::new();
-        
+
         for stock in stock_analysis {
             if let Some(kelly) = kelly_analysis.iter().find(|k| k.ticker == stock.ticker) {
-                let unified_metric = self.kelly_analyzer.calculate_unified_metric(stock, kelly)?;
+                let unified_metric = self.kelly_analyzer.calculate_unified_metric(stock, kelly, &benchmark_returns)?;
                 unified_metrics.push(unified_metric);
             }
         }
@@ -365,8 +759,139 @@ impl TradingBot {
         info!("🧪 Testing Python bridge...");
         
         self.python_bridge.test_python_bridge()?;
-        
+
         info!("✅ Python bridge test completed successfully");
         Ok(())
     }
+
+    /// Feeds `StreamManager` with live prices for a fixed number of polls,
+    /// reconnecting with exponential backoff whenever a poll fails.
+    ///
+    /// This repo has no raw exchange websocket client, so the feed is built on
+    /// top of the same `PythonBridge` calls the rest of the bot already uses;
+    /// a real low-latency feed would implement the same `ParsedMessage`
+    /// ingestion against a native exchange connector instead.
+    pub async fn stream_market_data(&self, symbols: Vec<String>, market: Market) -> Result<(), TradingBotError> {
+        const POLL_INTERVAL: Duration = Duration::from_secs(5);
+        const MAX_POLLS: usize = 12;
+        const RETURNS_WINDOW: usize = 252;
+
+        info!("📡 Streaming {:?} market data for {} symbols...", market, symbols.len());
+
+        let mut manager = StreamManager::new(RETURNS_WINDOW);
+        let mut backoff = ReconnectBackoff::new(Duration::from_secs(1), Duration::from_secs(60));
+
+        for poll in 0..MAX_POLLS {
+            for symbol in &symbols {
+                let price = match market {
+                    Market::Stock => self.python_bridge.analyze_stock_potential(symbol).map(|s| s.current_price),
+                    Market::Crypto => self.analyze_crypto().await.ok()
+                        .and_then(|crypto| {
+                            crypto.top_gainers.iter().chain(crypto.top_losers.iter())
+                                .find(|c| &c.symbol == symbol)
+                                .map(|c| c.current_price)
+                        })
+                        .ok_or_else(|| TradingBotError::DataProcessing(format!("No crypto data for {}", symbol))),
+                };
+
+                match price {
+                    Ok(price) => {
+                        backoff.reset();
+                        let message = ParsedMessage {
+                            exchange: "python_bridge".to_string(),
+                            symbol: symbol.clone(),
+                            pair: symbol.clone(),
+                            msg_type: MessageType::Ticker,
+                            timestamp_ms: Utc::now().timestamp_millis(),
+                            payload: serde_json::json!({ "price": price }),
+                        };
+                        manager.ingest(&message)?;
+                        info!("📈 {} @ {:.4} ({} returns tracked)", symbol, price, manager.returns(symbol).len());
+                    }
+                    Err(e) => {
+                        let delay = backoff.next_delay();
+                        warn!("Failed to poll {}: {} (retrying in {:?})", symbol, e, delay);
+                        tokio::time::sleep(delay).await;
+                    }
+                }
+            }
+
+            if poll + 1 < MAX_POLLS {
+                tokio::time::sleep(POLL_INTERVAL).await;
+            }
+        }
+
+        info!("✅ Streaming session complete");
+        Ok(())
+    }
+
+    /// Submits a buy order for every allocation in a portfolio summary,
+    /// routing through the paper broker when `dry_run` is set and the
+    /// configured live broker otherwise.
+    pub async fn execute_trades(&self, summary: &PortfolioSummary, dry_run: bool) -> Result<Vec<ExecutionReport>, TradingBotError> {
+        info!("💸 Executing {} trades (dry_run={})...", summary.allocations.len(), dry_run);
+
+        let broker: Box<dyn Broker> = if dry_run {
+            Box::new(PaperBroker::new())
+        } else {
+            Box::new(LiveBroker::new(self.config.execution.clone()))
+        };
+
+        let mut reports = Vec::with_capacity(summary.allocations.len());
+
+        for allocation in &summary.allocations {
+            if allocation.shares_to_buy == 0 {
+                continue;
+            }
+
+            let order = OrderRequest::from_allocation(allocation);
+            match broker.submit_order(&order).await {
+                Ok(report) => reports.push(report),
+                Err(e) => warn!("Failed to execute order for {}: {}", allocation.ticker, e),
+            }
+        }
+
+        info!("✅ Execution complete: {} reports", reports.len());
+        Ok(reports)
+    }
+
+    /// Builds a liquidity ladder across `[low, high]` for `ticker` and
+    /// submits it as resting limit orders.
+    #[allow(clippy::too_many_arguments)]
+    pub async fn replicate_ladder(
+        &self,
+        ticker: String,
+        low: f64,
+        high: f64,
+        rungs: usize,
+        mode: LadderMode,
+        total_quantity: u32,
+        side: OrderSide,
+        dry_run: bool,
+    ) -> Result<Vec<ExecutionReport>, TradingBotError> {
+        info!("🪜 Replicating {:?} liquidity ladder for {} ({} rungs)...", mode, ticker, rungs);
+
+        let ladder = LiquidityLadder::new(ticker, low, high, rungs, mode)?;
+        let orders = ladder.build_orders(total_quantity, side);
+
+        let broker: Box<dyn Broker> = if dry_run {
+            Box::new(PaperBroker::new())
+        } else {
+            Box::new(LiveBroker::new(self.config.execution.clone()))
+        };
+
+        let mut reports = Vec::with_capacity(orders.len());
+        for order in &orders {
+            if order.quantity == 0 {
+                continue;
+            }
+            match broker.submit_order(order).await {
+                Ok(report) => reports.push(report),
+                Err(e) => warn!("Failed to submit ladder rung for {}: {}", order.ticker, e),
+            }
+        }
+
+        info!("✅ Ladder replication complete: {} reports", reports.len());
+        Ok(reports)
+    }
 }